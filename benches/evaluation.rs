@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use poker_in_rust::{
+    texas_hold_em::evaluation::{
+        bitmask_evaluator::BitmaskEvaluator, evaluator::Evaluator,
+        two_plus_two_evaluator::TwoPlusTwoEvaluator,
+    },
+    Card, Deck, ShuffleRng,
+};
+
+const HANDS: usize = 100_000;
+
+fn random_hands() -> Vec<[Card; 7]> {
+    let mut rng = ShuffleRng::seeded(0);
+    (0..HANDS)
+        .map(|_| {
+            let deck = Deck::new().shuffle_with(&mut rng);
+            deck.cards[..7].try_into().unwrap()
+        })
+        .collect()
+}
+
+fn bench_evaluator(c: &mut Criterion, name: &str, evaluator: &dyn Evaluator) {
+    let hands = random_hands();
+    let mut group = c.benchmark_group("evaluate_hand");
+    group.throughput(Throughput::Elements(HANDS as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for hand in hands.iter() {
+                black_box(evaluator.evaluate_hand(black_box(hand)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn two_plus_two(c: &mut Criterion) {
+    let path = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
+    let evaluator =
+        TwoPlusTwoEvaluator::create_from_path(&path).expect("Could not load hand ranks");
+    bench_evaluator(c, "two_plus_two", &evaluator);
+}
+
+/// Evaluates every pair of hole cards on one board, adding the board to the evaluation once.
+fn shared_board(c: &mut Criterion) {
+    let path = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
+    let evaluator =
+        TwoPlusTwoEvaluator::create_from_path(&path).expect("Could not load hand ranks");
+    let cards = Deck::new().shuffle_with(&mut ShuffleRng::seeded(0)).cards;
+    let (board, remaining) = cards.split_at(5);
+    let hole_cards: Vec<(Card, Card)> = remaining
+        .iter()
+        .enumerate()
+        .flat_map(|(i, first)| remaining[i + 1..].iter().map(|second| (*first, *second)))
+        .collect();
+
+    let mut group = c.benchmark_group("shared_board");
+    group.throughput(Throughput::Elements(hole_cards.len() as u64));
+    group.bench_function("two_plus_two", |b| {
+        b.iter(|| {
+            let partial = evaluator.add_cards(evaluator.start_evaluation(), black_box(board));
+            for (first, second) in hole_cards.iter() {
+                let partial = evaluator.add_cards(partial, &[*first, *second]);
+                black_box(evaluator.finish_evaluation(partial));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bitmask(c: &mut Criterion) {
+    bench_evaluator(c, "bitmask", &BitmaskEvaluator);
+}
+
+fn card_index(c: &mut Criterion) {
+    let cards = Deck::new().cards;
+    c.bench_function("card_index", |b| {
+        b.iter(|| {
+            for card in cards.iter() {
+                black_box(black_box(card).index());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, two_plus_two, shared_board, bitmask, card_index);
+criterion_main!(benches);
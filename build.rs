@@ -2,27 +2,25 @@ use anyhow::*;
 use fs_extra::copy_items;
 use fs_extra::dir::CopyOptions;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::{env, fs};
 
+#[allow(dead_code)]
+#[path = "src/texas_hold_em/evaluation/bitmask.rs"]
+mod bitmask;
+#[allow(dead_code)]
+#[path = "src/texas_hold_em/evaluation/hand_ranks_generator.rs"]
+mod hand_ranks_generator;
+
 fn main() -> Result<()> {
+    if env::var_os("CARGO_FEATURE_TWO_PLUS_TWO").is_none() {
+        return Ok(());
+    }
+
     let handranks_data = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
 
     if !handranks_data.exists() {
-        let make_command = Command::new("make")
-            .current_dir(
-                handranks_data
-                    .parent()
-                    .expect("Could not find handranks data parent directory"),
-            )
-            .output();
-        if let Err(make_error) = make_command {
-            if let std::io::ErrorKind::NotFound = make_error.kind() { 
-                return Err(anyhow!("make not found. Check your path, or install make."));
-            } else {
-                return Err(anyhow!("Unexpected error occured making HandRanks.Dat: {}", make_error));
-            }
-        }
+        hand_ranks_generator::write_to(&handranks_data)
+            .map_err(|err| anyhow!("Unexpected error occured generating HandRanks.dat: {}", err))?;
     }
 
     let out_dir = get_output_path();
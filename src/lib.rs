@@ -1,10 +1,16 @@
 pub mod poker;
 pub mod texas_hold_em;
 
+pub use poker::blinds::BlindLevel;
+pub use poker::blinds::BlindStructure;
 pub use poker::card::Card;
+pub use poker::card::ParseCardError;
+pub use poker::card_set::CardSet;
 pub use poker::deck::Deck;
 pub use poker::hand::Hand;
 pub use poker::player;
 pub use poker::pot::Pot;
 pub use poker::rank::Rank;
+pub use poker::rng::RngSource;
+pub use poker::rng::ShuffleRng;
 pub use poker::suit::Suit;
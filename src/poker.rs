@@ -1,7 +1,10 @@
+pub mod blinds;
 pub mod card;
+pub mod card_set;
 pub mod deck;
 pub mod hand;
 pub mod player;
 pub mod pot;
 pub mod rank;
+pub mod rng;
 pub mod suit;
@@ -0,0 +1,211 @@
+use std::fmt::Display;
+
+/// The forced bets for a single hand.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BlindLevel {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub ante: u32,
+    /// Whether the big blind pays the ante for every player at the table, instead of each player paying their own.
+    pub big_blind_ante: bool,
+}
+
+impl BlindLevel {
+    pub fn new(small_blind: u32, big_blind: u32) -> Self {
+        BlindLevel {
+            small_blind,
+            big_blind,
+            ante: 0,
+            big_blind_ante: false,
+        }
+    }
+
+    pub fn with_ante(self, ante: u32) -> Self {
+        BlindLevel {
+            ante,
+            big_blind_ante: false,
+            ..self
+        }
+    }
+
+    pub fn with_big_blind_ante(self, ante: u32) -> Self {
+        BlindLevel {
+            ante,
+            big_blind_ante: true,
+            ..self
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.big_blind == 0 {
+            return Err("Big blind must be greater than zero".to_string());
+        }
+        if self.small_blind > self.big_blind {
+            return Err(format!(
+                "Small blind ({}) is greater than big blind ({})",
+                self.small_blind, self.big_blind
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Display for BlindLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Blinds: {}/{}", self.small_blind, self.big_blind)?;
+        match (self.ante, self.big_blind_ante) {
+            (0, _) => Ok(()),
+            (ante, false) => write!(f, ", ante {}", ante),
+            (ante, true) => write!(f, ", big blind ante {}", ante),
+        }
+    }
+}
+
+/// A blind level that applies from a given hand onwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScheduledLevel {
+    pub starting_hand: u32,
+    pub level: BlindLevel,
+}
+
+/// The blind levels used over the course of a game, scheduled by hand count.
+///
+/// Cash games use a single fixed level, while tournaments increase the blinds as more hands are played.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlindStructure {
+    levels: Vec<ScheduledLevel>,
+}
+
+impl BlindStructure {
+    /// Creates a blind structure from a schedule of levels.
+    ///
+    /// The first level must start at hand zero, and each level must start after the previous one.
+    pub fn new(levels: Vec<ScheduledLevel>) -> Result<Self, String> {
+        match levels.first() {
+            None => return Err("Blind structure requires at least one level".to_string()),
+            Some(first) if first.starting_hand != 0 => {
+                return Err("First blind level must start at hand zero".to_string())
+            }
+            _ => (),
+        }
+
+        if levels
+            .windows(2)
+            .any(|pair| pair[0].starting_hand >= pair[1].starting_hand)
+        {
+            return Err("Blind levels must start in increasing hand order".to_string());
+        }
+
+        for scheduled in levels.iter() {
+            scheduled.level.validate()?;
+        }
+
+        Ok(BlindStructure { levels })
+    }
+
+    /// Creates a blind structure that stays at the same level, as in a cash game.
+    pub fn fixed(level: BlindLevel) -> Result<Self, String> {
+        BlindStructure::new(vec![ScheduledLevel {
+            starting_hand: 0,
+            level,
+        }])
+    }
+
+    /// Creates a blind structure that moves to the next level every `hands_per_level` hands.
+    ///
+    /// The final level is used for the rest of the game.
+    pub fn increasing_every(hands_per_level: u32, levels: Vec<BlindLevel>) -> Result<Self, String> {
+        if hands_per_level == 0 {
+            return Err("Hands per level must be greater than zero".to_string());
+        }
+
+        let levels = levels
+            .into_iter()
+            .enumerate()
+            .map(|(i, level)| ScheduledLevel {
+                starting_hand: i as u32 * hands_per_level,
+                level,
+            })
+            .collect();
+        BlindStructure::new(levels)
+    }
+
+    /// The blind level used for a hand, counting from zero.
+    pub fn level_for_hand(&self, hand_number: u32) -> BlindLevel {
+        self.levels
+            .iter()
+            .take_while(|scheduled| scheduled.starting_hand <= hand_number)
+            .last()
+            .map(|scheduled| scheduled.level)
+            .expect("Blind structure always has a level starting at hand zero")
+    }
+
+    pub fn levels(&self) -> &[ScheduledLevel] {
+        &self.levels
+    }
+}
+
+impl Default for BlindStructure {
+    fn default() -> Self {
+        BlindStructure {
+            levels: vec![ScheduledLevel {
+                starting_hand: 0,
+                level: BlindLevel::new(5, 10),
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlindLevel, BlindStructure, ScheduledLevel};
+    use rstest::rstest;
+
+    #[test]
+    fn fixed_structure_never_changes_level() {
+        let level = BlindLevel::new(1, 2);
+        let structure = BlindStructure::fixed(level).unwrap();
+        assert_eq!(level, structure.level_for_hand(0));
+        assert_eq!(level, structure.level_for_hand(1000));
+    }
+
+    #[rstest]
+    #[case(0, BlindLevel::new(5, 10))]
+    #[case(9, BlindLevel::new(5, 10))]
+    #[case(10, BlindLevel::new(10, 20))]
+    #[case(25, BlindLevel::new(25, 50).with_ante(5))]
+    #[case(500, BlindLevel::new(25, 50).with_ante(5))]
+    fn increasing_structure_moves_up_levels(#[case] hand: u32, #[case] expected: BlindLevel) {
+        let structure = BlindStructure::increasing_every(
+            10,
+            vec![
+                BlindLevel::new(5, 10),
+                BlindLevel::new(10, 20),
+                BlindLevel::new(25, 50).with_ante(5),
+            ],
+        )
+        .unwrap();
+        assert_eq!(expected, structure.level_for_hand(hand));
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![ScheduledLevel { starting_hand: 5, level: BlindLevel::new(5, 10) }])]
+    #[case(vec![
+        ScheduledLevel { starting_hand: 0, level: BlindLevel::new(5, 10) },
+        ScheduledLevel { starting_hand: 0, level: BlindLevel::new(10, 20) },
+    ])]
+    #[case(vec![ScheduledLevel { starting_hand: 0, level: BlindLevel::new(20, 10) }])]
+    #[case(vec![ScheduledLevel { starting_hand: 0, level: BlindLevel::new(0, 0) }])]
+    fn rejects_invalid_schedules(#[case] levels: Vec<ScheduledLevel>) {
+        assert!(BlindStructure::new(levels).is_err())
+    }
+
+    #[rstest]
+    #[case(BlindLevel::new(5, 10), "Blinds: 5/10")]
+    #[case(BlindLevel::new(5, 10).with_ante(1), "Blinds: 5/10, ante 1")]
+    #[case(BlindLevel::new(5, 10).with_big_blind_ante(10), "Blinds: 5/10, big blind ante 10")]
+    fn can_be_displayed(#[case] level: BlindLevel, #[case] expected_string: String) {
+        assert_eq!(expected_string, level.to_string())
+    }
+}
@@ -1,4 +1,6 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
+
+use strum::IntoEnumIterator;
 
 use crate::{Rank, Suit};
 
@@ -10,7 +12,7 @@ pub struct Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.rank.partial_cmp(&other.rank)
+        Some(self.cmp(other))
     }
 }
 
@@ -24,6 +26,18 @@ impl Card {
     pub fn new(suit: Suit, rank: Rank) -> Self {
         Card { suit, rank }
     }
+
+    /// A number from 0 to 51 identifying the card, ordered by rank and then suit, so 2♣ is 0 and A♠ is 51.
+    pub const fn index(self) -> u8 {
+        self.rank as u8 * 4 + self.suit as u8
+    }
+
+    /// The card with the given [`Card::index`], or `None` if the index is 52 or more.
+    pub fn from_index(index: u8) -> Option<Self> {
+        let rank = Rank::iter().nth(index as usize / 4)?;
+        let suit = Suit::iter().nth(index as usize % 4)?;
+        Some(Card::new(suit, rank))
+    }
 }
 
 impl fmt::Display for Card {
@@ -32,16 +46,175 @@ impl fmt::Display for Card {
     }
 }
 
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses a card like "As", "Td" or "10♥".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_cards(s)?.as_slice() {
+            [card] => Ok(*card),
+            cards => Err(ParseCardError::WrongNumberOfCards {
+                expected: 1,
+                found: cards.len(),
+            }),
+        }
+    }
+}
+
+/// Why a card, or list of cards, could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    InvalidRank(String),
+    InvalidSuit(String),
+    /// A rank was not followed by a suit.
+    MissingSuit(String),
+    WrongNumberOfCards {
+        expected: usize,
+        found: usize,
+    },
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::InvalidRank(rank) => write!(f, "\"{}\" is not a rank", rank),
+            ParseCardError::InvalidSuit(suit) => write!(f, "\"{}\" is not a suit", suit),
+            ParseCardError::MissingSuit(card) => write!(f, "\"{}\" is missing a suit", card),
+            ParseCardError::WrongNumberOfCards { expected, found } => {
+                write!(f, "Expected {} cards but found {}", expected, found)
+            }
+            ParseCardError::DuplicateCard(card) => write!(f, "{} appears more than once", card),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+/// Parses a list of distinct cards, like "As Kd", "Th9h" or "A♠, 10♥".
+///
+/// Cards are a rank (2-9, T or 10, J, Q, K, A) followed by a suit (c, d, h, s or ♣, ♦, ♥, ♠). They
+/// can be separated by whitespace or commas, or written together.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    let mut cards: Vec<Card> = Vec::new();
+    for word in s
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+    {
+        let mut rest = word;
+        while !rest.is_empty() {
+            let rank_len = if rest.starts_with("10") {
+                2
+            } else {
+                rest.chars().next().map_or(0, char::len_utf8)
+            };
+            let (rank, after_rank) = rest.split_at(rank_len);
+            let rank = rank.parse::<Rank>()?;
+            let suit_len = after_rank
+                .chars()
+                .next()
+                .map(char::len_utf8)
+                .ok_or_else(|| ParseCardError::MissingSuit(word.to_string()))?;
+            let (suit, after_suit) = after_rank.split_at(suit_len);
+            let card = Card::new(suit.parse::<Suit>()?, rank);
+
+            if cards.contains(&card) {
+                return Err(ParseCardError::DuplicateCard(card));
+            }
+            cards.push(card);
+            rest = after_suit;
+        }
+    }
+    Ok(cards)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Rank, Suit};
+    use crate::{Deck, Rank, Suit};
 
-    use super::Card;
+    use super::{parse_cards, Card, ParseCardError};
     use rstest::rstest;
 
+    #[rstest]
+    #[case(Card::new(Suit::Club, Rank::Two), 0)]
+    #[case(Card::new(Suit::Spade, Rank::Two), 3)]
+    #[case(Card::new(Suit::Club, Rank::Three), 4)]
+    #[case(Card::new(Suit::Spade, Rank::Ace), 51)]
+    fn has_index(#[case] card: Card, #[case] expected_index: u8) {
+        assert_eq!(expected_index, card.index());
+        assert_eq!(Some(card), Card::from_index(expected_index));
+    }
+
+    #[test]
+    fn indices_follow_new_deck_order() {
+        for (index, card) in Deck::new().cards.into_iter().enumerate() {
+            assert_eq!(index as u8, card.index());
+        }
+    }
+
+    #[test]
+    fn has_no_card_for_index_past_deck() {
+        assert_eq!(None, Card::from_index(52));
+    }
+
     #[rstest]
     #[case(Card::new(Suit::Spade, Rank::Ace), r#"A♠"#)]
     fn can_be_displayed(#[case] card: Card, #[case] expected_string: String) {
         assert_eq!(expected_string, card.to_string())
     }
+
+    #[rstest]
+    #[case("As", Card::new(Suit::Spade, Rank::Ace))]
+    #[case("Td", Card::new(Suit::Diamond, Rank::Ten))]
+    #[case("10h", Card::new(Suit::Heart, Rank::Ten))]
+    #[case("2c", Card::new(Suit::Club, Rank::Two))]
+    #[case("kS", Card::new(Suit::Spade, Rank::King))]
+    #[case("A♠", Card::new(Suit::Spade, Rank::Ace))]
+    #[case("10♥", Card::new(Suit::Heart, Rank::Ten))]
+    fn can_be_parsed(#[case] string: &str, #[case] expected_card: Card) {
+        assert_eq!(Ok(expected_card), string.parse());
+    }
+
+    #[test]
+    fn parses_displayed_cards() {
+        for card in Deck::new().cards {
+            assert_eq!(Ok(card), card.to_string().parse());
+        }
+    }
+
+    #[rstest]
+    #[case("", ParseCardError::WrongNumberOfCards { expected: 1, found: 0 })]
+    #[case("AsKs", ParseCardError::WrongNumberOfCards { expected: 1, found: 2 })]
+    #[case("Xs", ParseCardError::InvalidRank("X".to_string()))]
+    #[case("Ax", ParseCardError::InvalidSuit("x".to_string()))]
+    #[case("A", ParseCardError::MissingSuit("A".to_string()))]
+    fn reports_invalid_card(#[case] string: &str, #[case] expected_error: ParseCardError) {
+        assert_eq!(Err(expected_error), string.parse::<Card>());
+    }
+
+    #[rstest]
+    #[case("As Kd")]
+    #[case("AsKd")]
+    #[case("A♠, K♦")]
+    #[case(" As,Kd ")]
+    fn parses_card_lists(#[case] string: &str) {
+        assert_eq!(
+            Ok(vec![
+                Card::new(Suit::Spade, Rank::Ace),
+                Card::new(Suit::Diamond, Rank::King)
+            ]),
+            parse_cards(string)
+        );
+    }
+
+    #[test]
+    fn card_lists_cannot_repeat_cards() {
+        assert_eq!(
+            Err(ParseCardError::DuplicateCard(Card::new(
+                Suit::Spade,
+                Rank::Ace
+            ))),
+            parse_cards("As Kd As")
+        );
+    }
 }
@@ -0,0 +1,299 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, BitOrAssign, Not, Sub},
+    str::FromStr,
+};
+
+use crate::{Card, Deck, Hand};
+
+use super::card::{parse_cards, ParseCardError};
+
+/// A set of cards stored as a 64-bit mask, with a bit set at each card's [`Card::index`].
+///
+/// Checking, adding and removing cards take constant time, and sets are combined with `|`, `&`
+/// and `-`. Cards are iterated in index order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(pub u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    /// Every card in the deck.
+    pub const FULL: CardSet = CardSet((1 << 52) - 1);
+
+    pub fn new() -> Self {
+        CardSet::EMPTY
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & CardSet::bit(card) != 0
+    }
+
+    /// Adds the card, returning whether it was not already in the set.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let added = !self.contains(card);
+        self.0 |= CardSet::bit(card);
+        added
+    }
+
+    /// Removes the card, returning whether it was in the set.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !CardSet::bit(card);
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// The cards in this set that are not in `other`.
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    pub fn is_disjoint(self, other: CardSet) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(self, other: CardSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    fn bit(card: Card) -> u64 {
+        1 << card.index()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, rhs: CardSet) -> CardSet {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, rhs: CardSet) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, rhs: CardSet) -> CardSet {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, rhs: CardSet) -> CardSet {
+        self.difference(rhs)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    /// Every card in the deck that is not in the set.
+    fn not(self) -> CardSet {
+        CardSet::FULL.difference(self)
+    }
+}
+
+/// Iterates over the cards in a [`CardSet`] in index order.
+#[derive(Debug, Clone)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Card::from_index(index as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut set = CardSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<T: IntoIterator<Item = Card>>(&mut self, iter: T) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> Self {
+        CardSet(CardSet::bit(card))
+    }
+}
+
+impl From<Hand> for CardSet {
+    fn from(hand: Hand) -> Self {
+        CardSet::from(hand.cards)
+    }
+}
+
+impl From<&Deck> for CardSet {
+    fn from(deck: &Deck) -> Self {
+        CardSet::from(deck.cards.as_slice())
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+}
+
+impl<const N: usize> From<[Card; N]> for CardSet {
+    fn from(cards: [Card; N]) -> Self {
+        cards.into_iter().collect()
+    }
+}
+
+impl FromStr for CardSet {
+    type Err = ParseCardError;
+
+    /// Parses a list of distinct cards, as in [`parse_cards`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_cards(s)?.into_iter().collect())
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, card) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Card, Deck, Hand, Rank, Suit};
+
+    use super::CardSet;
+
+    fn cards(s: &str) -> CardSet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn inserts_and_removes_cards() {
+        let ace = Card::new(Suit::Spade, Rank::Ace);
+        let mut set = CardSet::new();
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+        assert!(set.contains(ace));
+        assert_eq!(1, set.len());
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let first = cards("As Kd Qh");
+        let second = cards("Kd 2c");
+        assert_eq!(cards("As Kd Qh 2c"), first | second);
+        assert_eq!(cards("Kd"), first & second);
+        assert_eq!(cards("As Qh"), first - second);
+        assert!(cards("As").is_subset(first));
+        assert!(first.is_disjoint(cards("2c 3c")));
+        assert_eq!(49, (!first).len());
+    }
+
+    #[test]
+    fn iterates_in_index_order() {
+        let set = cards("As 2c Kd 2s");
+        assert_eq!(
+            vec![
+                Card::new(Suit::Club, Rank::Two),
+                Card::new(Suit::Spade, Rank::Two),
+                Card::new(Suit::Diamond, Rank::King),
+                Card::new(Suit::Spade, Rank::Ace),
+            ],
+            set.iter().collect::<Vec<Card>>()
+        );
+        assert_eq!(4, set.iter().len());
+    }
+
+    #[test]
+    fn full_deck_is_full_set() {
+        assert_eq!(CardSet::FULL, CardSet::from(&Deck::new()));
+        assert_eq!(
+            Deck::new().cards,
+            CardSet::FULL.iter().collect::<Vec<Card>>()
+        );
+    }
+
+    #[test]
+    fn converts_from_hands_and_boards() {
+        let hand: Hand = "Th9h".parse().unwrap();
+        let board = [
+            Card::new(Suit::Heart, Rank::Eight),
+            Card::new(Suit::Club, Rank::Two),
+            Card::new(Suit::Spade, Rank::King),
+        ];
+        assert_eq!(
+            cards("Th 9h 8h 2c Ks"),
+            CardSet::from(hand) | CardSet::from(board)
+        );
+    }
+
+    #[test]
+    fn can_be_displayed() {
+        assert_eq!("2♣ A♠", cards("As 2c").to_string());
+    }
+}
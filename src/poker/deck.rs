@@ -1,8 +1,11 @@
+use std::fmt::Display;
+
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use rand::Rng;
 use strum::IntoEnumIterator;
 
-use crate::{Card, Rank, Suit};
+use crate::{Card, CardSet, Hand, Rank, Suit};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Deck {
@@ -20,20 +23,110 @@ impl Deck {
         Deck { cards }
     }
 
+    /// A deck that deals the given hands and then the board, with the other cards below them.
+    ///
+    /// Hands are dealt two cards at a time in the order given, as the table deals one hand to
+    /// each player in seat order. A board of fewer than five cards is completed from the
+    /// remaining cards, which are in new deck order.
+    pub fn stacked(hands: &[Hand], board: &[Card]) -> Result<Self, DeckError> {
+        let top: Vec<Card> = hands
+            .iter()
+            .flat_map(|hand| hand.cards)
+            .chain(board.iter().copied())
+            .collect();
+        let mut stacked = CardSet::new();
+        for card in top.iter() {
+            if !stacked.insert(*card) {
+                return Err(DeckError::DuplicateCard(*card));
+            }
+        }
+
+        let mut deck = Deck::new().without(stacked);
+        deck.cards.extend(top.into_iter().rev());
+        Ok(deck)
+    }
+
     pub fn shuffle(self) -> Self {
+        self.shuffle_with(&mut thread_rng())
+    }
+
+    /// Shuffles using the given generator, so a seeded generator always produces the same order.
+    pub fn shuffle_with<R: Rng + ?Sized>(self, rng: &mut R) -> Self {
         let mut cards = self.cards;
-        cards.shuffle(&mut thread_rng());
+        cards.shuffle(rng);
         Deck { cards }
     }
 
     pub fn draw(&mut self) -> Option<Card> {
         self.cards.pop()
     }
+
+    /// Removes dead cards, such as cards already dealt, keeping the order of the rest.
+    ///
+    /// Returns the dead cards that were in the deck.
+    pub fn exclude(&mut self, dead: CardSet) -> CardSet {
+        let removed = CardSet::from(&*self) & dead;
+        self.cards.retain(|card| !dead.contains(*card));
+        removed
+    }
+
+    /// Removes the card, returning whether it was in the deck.
+    pub fn remove(&mut self, card: &Card) -> bool {
+        !self.exclude(CardSet::from(*card)).is_empty()
+    }
+
+    /// The deck without the given cards, such as cards known to be dealt elsewhere.
+    pub fn without(mut self, cards: impl Into<CardSet>) -> Self {
+        self.exclude(cards.into());
+        self
+    }
+
+    /// Deals the given cards, wherever they are in the deck.
+    ///
+    /// Leaves the deck unchanged and returns an error if a card is not in the deck or is asked for
+    /// twice.
+    pub fn deal_specific<const N: usize>(
+        &mut self,
+        cards: [Card; N],
+    ) -> Result<[Card; N], DeckError> {
+        let in_deck = CardSet::from(&*self);
+        let mut wanted = CardSet::new();
+        for card in cards {
+            if !in_deck.contains(card) {
+                return Err(DeckError::MissingCard(card));
+            }
+            if !wanted.insert(card) {
+                return Err(DeckError::DuplicateCard(card));
+            }
+        }
+        self.exclude(wanted);
+        Ok(cards)
+    }
 }
 
+/// Why cards could not be dealt from, or stacked in, a deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckError {
+    MissingCard(Card),
+    DuplicateCard(Card),
+}
+
+impl Display for DeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeckError::MissingCard(card) => write!(f, "{} is not in the deck", card),
+            DeckError::DuplicateCard(card) => write!(f, "{} is used more than once", card),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
 #[cfg(test)]
 mod tests {
-    use super::Deck;
+    use crate::{Card, CardSet, Hand, ShuffleRng};
+
+    use super::{Deck, DeckError};
 
     #[test]
     fn has_52_cards_at_creation() {
@@ -65,4 +158,109 @@ mod tests {
             .zip(shuffled_order.iter())
             .any(|pair| pair.0 != pair.1))
     }
+
+    #[test]
+    fn same_seed_shuffles_the_same() {
+        let first = Deck::new().shuffle_with(&mut ShuffleRng::seeded(7));
+        let second = Deck::new().shuffle_with(&mut ShuffleRng::seeded(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_seeds_shuffle_differently() {
+        let first = Deck::new().shuffle_with(&mut ShuffleRng::seeded(7));
+        let second = Deck::new().shuffle_with(&mut ShuffleRng::seeded(8));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn returns_excluded_cards_that_were_in_the_deck() {
+        let mut deck = Deck::new();
+        let king: Card = "Kd".parse().unwrap();
+        deck.cards.retain(|card| *card != king);
+        let removed = deck.exclude("As Kd".parse().unwrap());
+        assert_eq!("As".parse::<CardSet>().unwrap(), removed);
+        assert_eq!(50, deck.cards.len());
+        assert!(CardSet::from(&deck).is_disjoint("As Kd".parse().unwrap()));
+    }
+
+    #[test]
+    fn excludes_dead_cards_keeping_order() {
+        let mut deck = Deck::new().shuffle_with(&mut ShuffleRng::seeded(7));
+        let dead: CardSet = "2c Th 9h".parse().unwrap();
+        let expected_order: Vec<_> = deck
+            .cards
+            .iter()
+            .copied()
+            .filter(|card| !dead.contains(*card))
+            .collect();
+        deck.exclude(dead);
+        assert_eq!(expected_order, deck.cards);
+    }
+
+    fn card(s: &str) -> Card {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn removes_card() {
+        let mut deck = Deck::new();
+        assert!(deck.remove(&card("As")));
+        assert!(!deck.remove(&card("As")));
+        assert_eq!(51, deck.cards.len());
+    }
+
+    #[test]
+    fn can_be_created_without_cards() {
+        let hand: Hand = "Th9h".parse().unwrap();
+        let deck = Deck::new().without(hand);
+        assert_eq!(50, deck.cards.len());
+        assert!(CardSet::from(&deck).is_disjoint(hand.into()));
+    }
+
+    #[test]
+    fn deals_specific_cards() {
+        let mut deck = Deck::new().shuffle_with(&mut ShuffleRng::seeded(7));
+        assert_eq!(
+            Ok([card("Kd"), card("2c")]),
+            deck.deal_specific([card("Kd"), card("2c")])
+        );
+        assert_eq!(50, deck.cards.len());
+        assert_eq!(
+            Err(DeckError::MissingCard(card("Kd"))),
+            deck.deal_specific([card("As"), card("Kd")])
+        );
+        assert_eq!(
+            Err(DeckError::DuplicateCard(card("As"))),
+            deck.deal_specific([card("As"), card("As")])
+        );
+        assert_eq!(50, deck.cards.len());
+    }
+
+    #[test]
+    fn stacked_deck_deals_hands_then_board() {
+        let hands: [Hand; 2] = ["AsKs".parse().unwrap(), "7c2d".parse().unwrap()];
+        let board = [card("Qs"), card("Js"), card("Ts")];
+        let mut deck = Deck::stacked(&hands, &board).unwrap();
+
+        let dealt: Vec<Card> = (0..7).map(|_| deck.draw().unwrap()).collect();
+        assert_eq!(
+            "As Ks 7c 2d Qs Js Ts"
+                .split(' ')
+                .map(card)
+                .collect::<Vec<Card>>(),
+            dealt
+        );
+        assert_eq!(45, deck.cards.len());
+        assert!(CardSet::from(&deck).is_disjoint(dealt.as_slice().into()));
+    }
+
+    #[test]
+    fn cannot_stack_a_card_twice() {
+        let hands: [Hand; 1] = ["AsKs".parse().unwrap()];
+        assert_eq!(
+            Err(DeckError::DuplicateCard(card("As"))),
+            Deck::stacked(&hands, &[card("As")])
+        );
+    }
 }
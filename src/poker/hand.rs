@@ -1,7 +1,9 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use crate::Card;
 
+use super::card::{parse_cards, ParseCardError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Hand {
     pub cards: [Card; 2],
@@ -18,3 +20,55 @@ impl Display for Hand {
         write!(f, "{}, {}", self.cards[0], self.cards[1])
     }
 }
+
+impl FromStr for Hand {
+    type Err = ParseCardError;
+
+    /// Parses two distinct cards, like "As Kd" or "Th9h".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = parse_cards(s)?;
+        let found = cards.len();
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseCardError::WrongNumberOfCards { expected: 2, found })?;
+        Ok(Hand::new(cards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{poker::card::ParseCardError, Card, Rank, Suit};
+
+    use super::Hand;
+
+    #[rstest]
+    #[case("Th9h")]
+    #[case("Th 9h")]
+    #[case("10♥, 9♥")]
+    fn can_be_parsed(#[case] string: &str) {
+        let expected_hand = Hand::new([
+            Card::new(Suit::Heart, Rank::Ten),
+            Card::new(Suit::Heart, Rank::Nine),
+        ]);
+        assert_eq!(Ok(expected_hand), string.parse());
+    }
+
+    #[test]
+    fn parses_displayed_hand() {
+        let hand: Hand = "As Kd".parse().unwrap();
+        assert_eq!(Ok(hand), hand.to_string().parse());
+    }
+
+    #[rstest]
+    #[case("As", ParseCardError::WrongNumberOfCards { expected: 2, found: 1 })]
+    #[case("As Kd Qh", ParseCardError::WrongNumberOfCards { expected: 2, found: 3 })]
+    #[case(
+        "AsAs",
+        ParseCardError::DuplicateCard(Card::new(Suit::Spade, Rank::Ace))
+    )]
+    fn reports_invalid_hand(#[case] string: &str, #[case] expected_error: ParseCardError) {
+        assert_eq!(Err(expected_error), string.parse::<Hand>());
+    }
+}
@@ -13,6 +13,9 @@ pub struct Player<S: PlayerState> {
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Active {
     pub chips_bet_in_stage: u32,
+    pub has_acted: bool,
+    /// Set when a short all-in raises a player who has already acted, who may then only call.
+    pub raise_closed: bool,
 }
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Folded {}
@@ -56,14 +59,48 @@ impl Player<Active> {
             ));
         }
 
-        if amount > pot.minimum_bet {
-            pot.minimum_bet = amount;
-        }
+        self.put_in_pot(amount, pot);
+        Ok(())
+    }
+
+    /// Bets all of the player's remaining chips, even if that is less than the minimum bet.
+    pub fn all_in(&mut self, pot: &mut Pot) -> u32 {
+        let amount = self.chips;
+        self.put_in_pot(amount, pot);
+        amount
+    }
+
+    /// Posts a forced bet such as a blind, or as many chips as the player has left.
+    ///
+    /// Counts towards the player's bet in the stage, but does not count as the player having acted.
+    pub fn post_blind(&mut self, amount: u32, pot: &mut Pot) -> u32 {
+        let amount = amount.min(self.chips);
+        self.put_in_pot(amount, pot);
+        amount
+    }
+
+    /// Posts an ante, or as many chips as the player has left.
+    ///
+    /// Antes go straight into the pot and do not count towards the player's bet in the stage.
+    pub fn post_ante(&mut self, amount: u32, pot: &mut Pot) -> u32 {
+        let amount = amount.min(self.chips);
+        self.chips -= amount;
+        pot.add_chips(self.id, amount);
+        amount
+    }
 
+    pub fn is_all_in(&self) -> bool {
+        self.chips == 0
+    }
+
+    fn put_in_pot(&mut self, amount: u32, pot: &mut Pot) {
         self.chips -= amount;
         self.state.chips_bet_in_stage += amount;
-        pot.chips += amount;
-        Ok(())
+        pot.add_chips(self.id, amount);
+
+        if self.state.chips_bet_in_stage > pot.minimum_bet {
+            pot.minimum_bet = self.state.chips_bet_in_stage;
+        }
     }
 }
 
@@ -103,7 +140,7 @@ impl Display for Player<Folded> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct PlayerId(pub u8);
 impl Display for PlayerId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -145,21 +182,62 @@ mod tests {
     #[test]
     fn cannot_bet_less_than_minimum_pot_bet() {
         let mut player = create_test_player(0);
-        let mut pot = Pot {
-            chips: 50,
-            minimum_bet: 10,
-        };
+        let mut pot = Pot::new(50, 10);
         assert!(player.bet(5, &mut pot).is_err());
     }
 
     #[test]
     fn raising_increases_minimum_bet() {
         let mut player = create_test_player(0);
-        let mut pot = Pot {
-            chips: 50,
-            minimum_bet: 10,
-        };
+        let mut pot = Pot::new(50, 10);
         _ = player.bet(20, &mut pot);
         assert_eq!(20, pot.minimum_bet)
     }
+
+    #[test]
+    fn can_go_all_in_for_less_than_minimum_bet() {
+        let mut player = create_test_player(0);
+        let mut pot = Pot::new(300, 150);
+        assert_eq!(100, player.all_in(&mut pot));
+        assert!(player.is_all_in());
+        assert_eq!(400, pot.chips);
+        assert_eq!(150, pot.minimum_bet);
+    }
+
+    #[test]
+    fn posting_blind_counts_towards_stage_bet() {
+        let mut player = create_test_player(0);
+        let mut pot = Pot::default();
+        assert_eq!(10, player.post_blind(10, &mut pot));
+        assert_eq!(10, player.state.chips_bet_in_stage);
+        assert_eq!(10, pot.minimum_bet);
+        assert!(!player.state.has_acted);
+    }
+
+    #[test]
+    fn posting_ante_does_not_count_towards_stage_bet() {
+        let mut player = create_test_player(0);
+        let mut pot = Pot::default();
+        assert_eq!(5, player.post_ante(5, &mut pot));
+        assert_eq!(0, player.state.chips_bet_in_stage);
+        assert_eq!(0, pot.minimum_bet);
+        assert_eq!(5, pot.chips);
+    }
+
+    #[test]
+    fn short_stacked_player_posts_remaining_chips() {
+        let mut player = create_test_player(0);
+        let mut pot = Pot::default();
+        assert_eq!(100, player.post_blind(200, &mut pot));
+        assert!(player.is_all_in());
+    }
+
+    #[test]
+    fn betting_records_player_contribution() {
+        let mut player = create_test_player(3);
+        let mut pot = Pot::default();
+        _ = player.bet(20, &mut pot);
+        _ = player.bet(30, &mut pot);
+        assert_eq!(50, pot.contribution(PlayerId(3)));
+    }
 }
@@ -1,35 +1,118 @@
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
-use crate::player::{Active, Player};
+use crate::player::{Active, Player, PlayerId};
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Pot {
     pub chips: u32,
     pub minimum_bet: u32,
+    /// The chips each player has put into the pot this round.
+    pub contributions: BTreeMap<PlayerId, u32>,
+}
+
+/// A share of the pot that can only be won by the players eligible for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SidePot {
+    pub chips: u32,
+    pub eligible_players: Vec<PlayerId>,
 }
 
 impl Pot {
     /// Distributes pot winnings to a list of players.
     ///
     /// Players should be ordered by distance to dealer to account for splitting of uneven chip counts.
-    pub fn deal_winnings(&mut self, mut winners: Vec<&mut Player<Active>>) {
-        let division = self.chips / winners.len() as u32;
-        winners
-            .iter_mut()
-            .for_each(|winner| winner.chips += division);
-
-        // Distribute any extra chips based on position to the dealer, ie. order in the vec
-        let odd_chips = self.chips % winners.len() as u32;
-        for i in 0..odd_chips {
-            winners[i as usize].chips += 1;
-        }
+    pub fn deal_winnings(&mut self, winners: Vec<&mut Player<Active>>) {
+        split_chips(self.chips, winners);
 
         self.chips = 0;
         self.minimum_bet = 0;
+        self.contributions.clear();
     }
 
     pub fn new(chips: u32, minimum_bet: u32) -> Self {
-        Pot { chips, minimum_bet }
+        Pot {
+            chips,
+            minimum_bet,
+            contributions: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_chips(&mut self, player: PlayerId, amount: u32) {
+        self.chips += amount;
+        *self.contributions.entry(player).or_default() += amount;
+    }
+
+    pub fn contribution(&self, player: PlayerId) -> u32 {
+        self.contributions.get(&player).copied().unwrap_or_default()
+    }
+
+    /// Splits the pot into a main pot and side pots based on how much each player has contributed.
+    ///
+    /// Only `players` still in the round are eligible to win, but chips from folded players stay in the pots.
+    pub fn side_pots(&self, players: &[Player<Active>]) -> Vec<SidePot> {
+        let mut levels = players
+            .iter()
+            .map(|player| self.contribution(player.id))
+            .collect::<Vec<u32>>();
+        levels.sort();
+        levels.dedup();
+
+        let mut side_pots = vec![];
+        let mut previous_level = 0;
+        for level in levels {
+            let chips = self
+                .contributions
+                .values()
+                .map(|&contribution| contribution.min(level) - contribution.min(previous_level))
+                .sum();
+            let eligible_players = players
+                .iter()
+                .filter(|player| self.contribution(player.id) >= level)
+                .map(|player| player.id)
+                .collect();
+            side_pots.push(SidePot {
+                chips,
+                eligible_players,
+            });
+            previous_level = level;
+        }
+
+        // Chips not attributed to a player, or bet beyond what any remaining player matched
+        let allocated_chips = side_pots.iter().map(|pot| pot.chips).sum::<u32>();
+        match side_pots.last_mut() {
+            Some(last_pot) => last_pot.chips += self.chips - allocated_chips,
+            None => side_pots.push(SidePot {
+                chips: self.chips,
+                eligible_players: players.iter().map(|player| player.id).collect(),
+            }),
+        }
+
+        side_pots.retain(|pot| pot.chips > 0);
+        side_pots
+    }
+}
+
+impl SidePot {
+    /// Distributes the side pot to a list of players, in the same way as [`Pot::deal_winnings`].
+    pub fn deal_winnings(&self, winners: Vec<&mut Player<Active>>) {
+        split_chips(self.chips, winners);
+    }
+}
+
+fn split_chips(chips: u32, mut winners: Vec<&mut Player<Active>>) {
+    if winners.is_empty() {
+        return;
+    }
+
+    let division = chips / winners.len() as u32;
+    winners
+        .iter_mut()
+        .for_each(|winner| winner.chips += division);
+
+    // Distribute any extra chips based on position to the dealer, ie. order in the vec
+    let odd_chips = chips % winners.len() as u32;
+    for i in 0..odd_chips {
+        winners[i as usize].chips += 1;
     }
 }
 
@@ -51,14 +134,18 @@ mod tests {
         Deck, Hand,
     };
 
-    use super::Pot;
+    use super::{Pot, SidePot};
 
     fn create_test_player(deck: &mut Deck, chips: u32) -> Player<Active> {
+        create_test_player_with_id(deck, 0, chips)
+    }
+
+    fn create_test_player_with_id(deck: &mut Deck, id: u8, chips: u32) -> Player<Active> {
         let hand = Hand::new([
             deck.draw().expect("Deck was empty"),
             deck.draw().expect("Deck was empty"),
         ]);
-        Player::<Active>::new(PlayerId(0), hand, chips)
+        Player::<Active>::new(PlayerId(id), hand, chips)
     }
 
     #[test]
@@ -72,10 +159,7 @@ mod tests {
             create_test_player(&mut deck, player_chips),
         ];
 
-        let mut pot = Pot {
-            chips: pot_chips,
-            minimum_bet: 0,
-        };
+        let mut pot = Pot::new(pot_chips, 0);
         pot.deal_winnings(players.iter_mut().collect());
 
         let pot_chips_per_player = pot_chips / players.len() as u32;
@@ -97,10 +181,7 @@ mod tests {
             create_test_player(&mut deck, player_chips),
         ];
 
-        let mut pot = Pot {
-            chips: pot_chips,
-            minimum_bet: 0,
-        };
+        let mut pot = Pot::new(pot_chips, 0);
         pot.deal_winnings(players.iter_mut().collect());
 
         // 5 into 3 does not go.. so divide based on player order
@@ -108,4 +189,88 @@ mod tests {
         assert_eq!(7, players[1].chips);
         assert_eq!(6, players[2].chips);
     }
+
+    #[test]
+    fn has_single_pot_when_contributions_are_equal() {
+        let mut deck = Deck::new();
+        let mut players = [
+            create_test_player_with_id(&mut deck, 1, 100),
+            create_test_player_with_id(&mut deck, 2, 100),
+        ];
+        let mut pot = Pot::default();
+        players.iter_mut().for_each(|player| {
+            player.bet(20, &mut pot).unwrap();
+        });
+
+        assert_eq!(
+            vec![SidePot {
+                chips: 40,
+                eligible_players: vec![PlayerId(1), PlayerId(2)]
+            }],
+            pot.side_pots(&players)
+        );
+    }
+
+    #[test]
+    fn creates_side_pots_for_all_in_players() {
+        let mut deck = Deck::new();
+        let mut players = [
+            create_test_player_with_id(&mut deck, 1, 20),
+            create_test_player_with_id(&mut deck, 2, 50),
+            create_test_player_with_id(&mut deck, 3, 100),
+            create_test_player_with_id(&mut deck, 4, 100),
+        ];
+        let mut pot = Pot::default();
+        players[0].all_in(&mut pot);
+        players[1].all_in(&mut pot);
+        players[2].bet(100, &mut pot).unwrap();
+        players[3].bet(100, &mut pot).unwrap();
+
+        assert_eq!(
+            vec![
+                SidePot {
+                    chips: 80,
+                    eligible_players: vec![PlayerId(1), PlayerId(2), PlayerId(3), PlayerId(4)]
+                },
+                SidePot {
+                    chips: 90,
+                    eligible_players: vec![PlayerId(2), PlayerId(3), PlayerId(4)]
+                },
+                SidePot {
+                    chips: 100,
+                    eligible_players: vec![PlayerId(3), PlayerId(4)]
+                },
+            ],
+            pot.side_pots(&players)
+        );
+    }
+
+    #[test]
+    fn folded_players_chips_stay_in_side_pots() {
+        let mut deck = Deck::new();
+        let mut players = vec![
+            create_test_player_with_id(&mut deck, 1, 20),
+            create_test_player_with_id(&mut deck, 2, 100),
+            create_test_player_with_id(&mut deck, 3, 100),
+        ];
+        let mut pot = Pot::default();
+        players[0].all_in(&mut pot);
+        players[1].bet(40, &mut pot).unwrap();
+        players[2].bet(40, &mut pot).unwrap();
+        players.remove(2);
+
+        assert_eq!(
+            vec![
+                SidePot {
+                    chips: 60,
+                    eligible_players: vec![PlayerId(1), PlayerId(2)]
+                },
+                SidePot {
+                    chips: 40,
+                    eligible_players: vec![PlayerId(2)]
+                },
+            ],
+            pot.side_pots(&players)
+        );
+    }
 }
@@ -1,7 +1,9 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use strum_macros::EnumIter;
 
+use super::card::ParseCardError;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, EnumIter, Clone, Copy, Hash)]
 pub enum Rank {
     Two,
@@ -19,6 +21,58 @@ pub enum Rank {
     Ace,
 }
 
+impl Rank {
+    /// The rank written out in words, like "Ace".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+            Rank::Ace => "Ace",
+        }
+    }
+
+    /// The single character used for the rank in hand notation, like 'T' for ten.
+    pub fn symbol(&self) -> char {
+        match self {
+            Rank::Ten => 'T',
+            rank => rank
+                .to_string()
+                .chars()
+                .next()
+                .expect("Rank should be displayed with at least one character"),
+        }
+    }
+
+    /// The plural of [`Rank::name`], like "Sixes".
+    pub fn plural_name(&self) -> &'static str {
+        match self {
+            Rank::Two => "Twos",
+            Rank::Three => "Threes",
+            Rank::Four => "Fours",
+            Rank::Five => "Fives",
+            Rank::Six => "Sixes",
+            Rank::Seven => "Sevens",
+            Rank::Eight => "Eights",
+            Rank::Nine => "Nines",
+            Rank::Ten => "Tens",
+            Rank::Jack => "Jacks",
+            Rank::Queen => "Queens",
+            Rank::King => "Kings",
+            Rank::Ace => "Aces",
+        }
+    }
+}
+
 impl fmt::Display for Rank {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let rank_string = match self {
@@ -40,8 +94,36 @@ impl fmt::Display for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    /// Parses a rank like "A", "t" or "10".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "2" => Ok(Rank::Two),
+            "3" => Ok(Rank::Three),
+            "4" => Ok(Rank::Four),
+            "5" => Ok(Rank::Five),
+            "6" => Ok(Rank::Six),
+            "7" => Ok(Rank::Seven),
+            "8" => Ok(Rank::Eight),
+            "9" => Ok(Rank::Nine),
+            "T" | "10" => Ok(Rank::Ten),
+            "J" => Ok(Rank::Jack),
+            "Q" => Ok(Rank::Queen),
+            "K" => Ok(Rank::King),
+            "A" => Ok(Rank::Ace),
+            _ => Err(ParseCardError::InvalidRank(s.to_string())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::poker::card::ParseCardError;
+
     use super::Rank;
     use rstest::rstest;
 
@@ -62,4 +144,48 @@ mod tests {
     fn can_be_displayed(#[case] rank: Rank, #[case] expected_string: String) {
         assert_eq!(expected_string, format!("{}", rank))
     }
+
+    #[rstest]
+    #[case(Rank::Two, "Two", "Twos")]
+    #[case(Rank::Six, "Six", "Sixes")]
+    #[case(Rank::Ace, "Ace", "Aces")]
+    fn has_names(#[case] rank: Rank, #[case] name: &str, #[case] plural_name: &str) {
+        assert_eq!(name, rank.name());
+        assert_eq!(plural_name, rank.plural_name());
+    }
+
+    #[test]
+    fn symbols_can_be_parsed() {
+        for rank in Rank::iter() {
+            assert_eq!(Ok(rank), rank.symbol().to_string().parse());
+        }
+        assert_eq!('T', Rank::Ten.symbol());
+    }
+
+    #[test]
+    fn parses_displayed_ranks() {
+        for rank in Rank::iter() {
+            assert_eq!(Ok(rank), rank.to_string().parse());
+        }
+    }
+
+    #[rstest]
+    #[case("T", Rank::Ten)]
+    #[case("t", Rank::Ten)]
+    #[case("q", Rank::Queen)]
+    fn can_be_parsed(#[case] string: &str, #[case] expected_rank: Rank) {
+        assert_eq!(Ok(expected_rank), string.parse());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("1")]
+    #[case("11")]
+    #[case("Ace")]
+    fn reports_invalid_rank(#[case] string: &str) {
+        assert_eq!(
+            Err(ParseCardError::InvalidRank(string.to_string())),
+            string.parse::<Rank>()
+        );
+    }
 }
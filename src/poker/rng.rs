@@ -0,0 +1,120 @@
+use rand::{rngs::OsRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Where a game gets the randomness used to shuffle the deck.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RngSource {
+    /// A seeded generator with a random seed, which can be read back to replay the session.
+    #[default]
+    Entropy,
+    /// A seeded generator, so the whole session can be reproduced from the seed.
+    Seeded(u64),
+    /// The operating system's cryptographically secure generator, for games where shuffles must be unpredictable.
+    Secure,
+}
+
+/// The random number generator used to shuffle decks during a game.
+#[derive(Debug, Clone)]
+pub struct ShuffleRng {
+    inner: Inner,
+}
+
+#[derive(Debug, Clone)]
+enum Inner {
+    Seeded { seed: u64, rng: Box<ChaCha8Rng> },
+    Secure(OsRng),
+}
+
+impl ShuffleRng {
+    pub fn new(source: RngSource) -> Self {
+        match source {
+            RngSource::Entropy => Self::seeded(OsRng.next_u64()),
+            RngSource::Seeded(seed) => Self::seeded(seed),
+            RngSource::Secure => ShuffleRng {
+                inner: Inner::Secure(OsRng),
+            },
+        }
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        ShuffleRng {
+            inner: Inner::Seeded {
+                seed,
+                rng: Box::new(ChaCha8Rng::seed_from_u64(seed)),
+            },
+        }
+    }
+
+    /// The seed the generator started from, or `None` for the secure generator.
+    pub fn seed(&self) -> Option<u64> {
+        match &self.inner {
+            Inner::Seeded { seed, .. } => Some(*seed),
+            Inner::Secure(_) => None,
+        }
+    }
+}
+
+impl Default for ShuffleRng {
+    fn default() -> Self {
+        ShuffleRng::new(RngSource::default())
+    }
+}
+
+impl RngCore for ShuffleRng {
+    fn next_u32(&mut self) -> u32 {
+        match &mut self.inner {
+            Inner::Seeded { rng, .. } => rng.next_u32(),
+            Inner::Secure(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match &mut self.inner {
+            Inner::Seeded { rng, .. } => rng.next_u64(),
+            Inner::Secure(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match &mut self.inner {
+            Inner::Seeded { rng, .. } => rng.fill_bytes(dest),
+            Inner::Secure(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match &mut self.inner {
+            Inner::Seeded { rng, .. } => rng.try_fill_bytes(dest),
+            Inner::Secure(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use super::{RngSource, ShuffleRng};
+
+    #[test]
+    fn same_seed_produces_same_numbers() {
+        let mut first = ShuffleRng::new(RngSource::Seeded(42));
+        let mut second = ShuffleRng::new(RngSource::Seeded(42));
+        for _ in 0..10 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+    }
+
+    #[test]
+    fn entropy_source_records_its_seed() {
+        let mut rng = ShuffleRng::new(RngSource::Entropy);
+        let mut replay = ShuffleRng::seeded(rng.seed().unwrap());
+        assert_eq!(rng.next_u64(), replay.next_u64());
+    }
+
+    #[test]
+    fn secure_source_has_no_seed() {
+        let rng = ShuffleRng::new(RngSource::Secure);
+        assert_eq!(None, rng.seed());
+    }
+}
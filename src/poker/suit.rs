@@ -1,7 +1,9 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use strum_macros::EnumIter;
 
+use super::card::ParseCardError;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, EnumIter, Clone, Copy, Hash)]
 pub enum Suit {
     Club,
@@ -22,8 +24,25 @@ impl fmt::Display for Suit {
     }
 }
 
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    /// Parses a suit from its letter, like "s" or "S", or its symbol, like "♠" or "♤".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "c" | "C" | "\u{2663}" | "\u{2667}" => Ok(Suit::Club),
+            "d" | "D" | "\u{2666}" | "\u{2662}" => Ok(Suit::Diamond),
+            "h" | "H" | "\u{2665}" | "\u{2661}" => Ok(Suit::Heart),
+            "s" | "S" | "\u{2660}" | "\u{2664}" => Ok(Suit::Spade),
+            _ => Err(ParseCardError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::poker::card::ParseCardError;
+
     use super::Suit;
     use rstest::rstest;
 
@@ -35,4 +54,21 @@ mod tests {
     fn can_be_displayed(#[case] suit: Suit, #[case] expected_string: String) {
         assert_eq!(expected_string, suit.to_string())
     }
+
+    #[rstest]
+    #[case("c", Suit::Club)]
+    #[case("D", Suit::Diamond)]
+    #[case("♥", Suit::Heart)]
+    #[case("♤", Suit::Spade)]
+    fn can_be_parsed(#[case] string: &str, #[case] expected_suit: Suit) {
+        assert_eq!(Ok(expected_suit), string.parse());
+    }
+
+    #[test]
+    fn reports_invalid_suit() {
+        assert_eq!(
+            Err(ParseCardError::InvalidSuit("x".to_string())),
+            "x".parse::<Suit>()
+        );
+    }
 }
@@ -1,6 +1,14 @@
+pub mod bots;
 pub mod console_player;
+pub mod equity;
 pub mod evaluation;
+pub mod game_config;
+pub mod hand_class;
+pub mod outs;
 pub mod player_driver;
+pub mod player_view;
+pub mod preflop;
+pub mod range;
 pub mod state;
 
 pub use state::stages::finished::Finished;
@@ -12,5 +20,11 @@ pub use state::stages::showdown::Showdown;
 pub use state::stages::turn::Turn;
 
 pub use console_player::ConsolePlayer;
+pub use game_config::BettingStructure;
+pub use game_config::GameConfig;
+pub use player_driver::IllegalMoveError;
+pub use player_driver::LegalAction;
 pub use player_driver::Move;
 pub use player_driver::PlayerDriver;
+pub use player_driver::PlayerDrivers;
+pub use player_view::PlayerView;
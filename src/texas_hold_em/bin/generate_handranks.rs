@@ -0,0 +1,19 @@
+use std::{env, path::PathBuf, process, time::Instant};
+
+use poker_in_rust::texas_hold_em::evaluation::hand_ranks_generator;
+
+fn main() {
+    let path = PathBuf::from(
+        env::args()
+            .nth(1)
+            .unwrap_or_else(|| "HandRanks.dat".to_string()),
+    );
+
+    println!("Generating {}", path.display());
+    let start = Instant::now();
+    if let Err(err) = hand_ranks_generator::write_to(&path) {
+        eprintln!("Could not write {}: {}", path.display(), err);
+        process::exit(1);
+    }
+    println!("Finished in {:.2?}", start.elapsed());
+}
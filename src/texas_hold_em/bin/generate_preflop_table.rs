@@ -0,0 +1,65 @@
+use std::{env, path::PathBuf, process, time::Instant};
+
+#[cfg(not(feature = "two-plus-two"))]
+use poker_in_rust::texas_hold_em::evaluation::evaluator::default_evaluator;
+#[cfg(feature = "two-plus-two")]
+use poker_in_rust::texas_hold_em::evaluation::two_plus_two_evaluator::TwoPlusTwoEvaluator;
+use poker_in_rust::texas_hold_em::{evaluation::evaluator::Evaluator, preflop::PreflopTable};
+
+const DEFAULT_ITERATIONS: u64 = 200_000;
+const SEED: u64 = 169;
+
+/// Usage: generate-preflop-table [PATH] [ITERATIONS]
+fn main() {
+    let mut args = env::args().skip(1);
+    let path = PathBuf::from(
+        args.next()
+            .unwrap_or_else(|| "preflop_equity.bin".to_string()),
+    );
+    let iterations = match args.next().map(|arg| arg.parse::<u64>()) {
+        None => DEFAULT_ITERATIONS,
+        Some(Ok(iterations)) => iterations,
+        Some(Err(err)) => {
+            eprintln!("Invalid number of iterations: {}", err);
+            process::exit(1);
+        }
+    };
+
+    let evaluator = create_evaluator();
+    println!(
+        "Generating {} with {} iterations per entry",
+        path.display(),
+        iterations
+    );
+    let start = Instant::now();
+    let table = PreflopTable::generate(evaluator.as_ref(), iterations, SEED, |class| {
+        println!("{} done after {:.2?}", class, start.elapsed())
+    })
+    .unwrap_or_else(|err| {
+        eprintln!("Could not generate the table: {}", err);
+        process::exit(1);
+    });
+    if let Err(err) = table.write_to(&path) {
+        eprintln!("Could not write {}: {}", path.display(), err);
+        process::exit(1);
+    }
+    println!("Finished in {:.2?}", start.elapsed());
+}
+
+#[cfg(feature = "two-plus-two")]
+fn create_evaluator() -> Box<dyn Evaluator> {
+    let exe_path = env::current_exe().expect("Could not find current exe");
+    let path = exe_path.parent().unwrap().join("HandRanks.dat");
+    match TwoPlusTwoEvaluator::create_from_path(&path) {
+        Ok(evaluator) => Box::new(evaluator),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "two-plus-two"))]
+fn create_evaluator() -> Box<dyn Evaluator> {
+    default_evaluator()
+}
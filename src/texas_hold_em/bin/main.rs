@@ -1,60 +1,263 @@
-use std::env;
+use std::{env, process, sync::Arc};
 
+#[cfg(not(feature = "two-plus-two"))]
+use poker_in_rust::texas_hold_em::evaluation::evaluator::default_evaluator;
+#[cfg(feature = "two-plus-two")]
+use poker_in_rust::texas_hold_em::evaluation::two_plus_two_evaluator::TwoPlusTwoEvaluator;
 use poker_in_rust::texas_hold_em::{
-    evaluation::{evaluator::Evaluator, two_plus_two_evaluator::TwoPlusTwoEvaluator},
+    bots::{
+        calling_station::CallingStation, random_bot::RandomBot, tight_aggressive::TightAggressive,
+    },
+    evaluation::evaluator::Evaluator,
+    game_config::GameConfigBuilder,
     state::{
         advancement::Advancement,
         stages::{finished::Finished, pre_round::PreRound},
     },
+    BettingStructure, ConsolePlayer, GameConfig, PlayerDriver, PlayerDrivers,
 };
+use poker_in_rust::RngSource;
+
+const USAGE: &str = "Usage: texas_hold_em [OPTIONS]
+
+Options:
+  --players <COUNT>          Number of players [default: 3]
+  --stack <CHIPS>            Starting stack for every player [default: 100]
+  --stacks <CHIPS,...>       Starting stack for each seat
+  --blinds <SMALL/BIG>       Small and big blinds [default: 5/10]
+  --ante <CHIPS>             Ante paid by every player
+  --big-blind-ante <CHIPS>   Ante paid by the big blind for the table
+  --betting <STRUCTURE>      no-limit, pot-limit or fixed-limit [default: no-limit]
+  --max-seats <COUNT>        Number of seats at the table [default: 9]
+  --names <NAME,...>         Name of each player
+  --seed <SEED>              Seed for shuffling, to replay a session
+  --secure-rng               Shuffle with the operating system's secure generator
+  --bots <COUNT>             Number of seats, counting back from the last, played by bots [default: 0]
+  --bot-kind <KIND>          tight-aggressive, calling-station or random [default: tight-aggressive]
+                             Bots use their default settings, and are seeded from --seed if given
+  --help                     Print this message";
 
 fn main() {
-    let exe_path = env::current_exe().expect("Could not find current exe");
-    let path = exe_path.parent().unwrap().join("HandRanks.dat");
-    let evaluator =
-        TwoPlusTwoEvaluator::create_from_path(&path).expect("Could not parse hand ranks db");
-    let evaluator: Box<dyn Evaluator> = Box::new(evaluator);
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(1);
+        }
+    };
+
+    let evaluator: Arc<dyn Evaluator> = Arc::from(create_evaluator());
 
-    let mut pre_round = PreRound::new(3).unwrap();
+    let bot_seed = match options.config.rng_source {
+        RngSource::Seeded(seed) => Some(seed),
+        _ => None,
+    };
+    let mut pre_round = PreRound::new(options.config);
+    if let Some(seed) = pre_round.table.rng.seed() {
+        println!("Shuffling with seed {}", seed);
+    }
+    let humans = pre_round.players.len() - options.bots;
+    let mut drivers: PlayerDrivers = pre_round
+        .players
+        .iter()
+        .enumerate()
+        .map(|(seat, player)| {
+            let driver: Box<dyn PlayerDriver> = match seat < humans {
+                true => Box::new(ConsolePlayer {}),
+                false => create_bot(
+                    options.bot_kind,
+                    &evaluator,
+                    bot_seed.map(|seed| seed.wrapping_add(seat as u64)),
+                ),
+            };
+            (player.id, driver)
+        })
+        .collect();
 
-    loop {
+    while !pre_round.is_game_over() {
         let stage = pre_round.start_round();
 
-        let stage = match stage.advance() {
+        let stage = match stage.advance(&mut drivers) {
             Advancement::NextStage(stage) => stage,
             Advancement::Finished(finished) => {
-                pre_round = finish_game(finished);
+                pre_round = finish_game(*finished);
                 continue;
             }
         };
 
-        let stage = match stage.advance() {
+        let stage = match stage.advance(&mut drivers) {
             Advancement::NextStage(stage) => stage,
             Advancement::Finished(finished) => {
-                pre_round = finish_game(finished);
+                pre_round = finish_game(*finished);
                 continue;
             }
         };
 
-        let stage = match stage.advance() {
+        let stage = match stage.advance(&mut drivers) {
             Advancement::NextStage(stage) => stage,
             Advancement::Finished(finished) => {
-                pre_round = finish_game(finished);
+                pre_round = finish_game(*finished);
                 continue;
             }
         };
 
-        let stage = match stage.advance() {
+        let stage = match stage.advance(&mut drivers) {
             Advancement::NextStage(stage) => stage,
             Advancement::Finished(finished) => {
-                pre_round = finish_game(finished);
+                pre_round = finish_game(*finished);
                 continue;
             }
         };
 
-        let finished = stage.finish(&evaluator);
+        let finished = stage.finish(evaluator.as_ref());
         pre_round = finish_game(finished);
     }
+
+    for player in pre_round.players.iter() {
+        println!(
+            "{} wins with {} chips",
+            pre_round.table.player_name(player.id),
+            player.chips
+        );
+    }
+}
+
+#[cfg(feature = "two-plus-two")]
+fn create_evaluator() -> Box<dyn Evaluator> {
+    let exe_path = env::current_exe().expect("Could not find current exe");
+    let path = exe_path.parent().unwrap().join("HandRanks.dat");
+    match TwoPlusTwoEvaluator::create_from_path(&path) {
+        Ok(evaluator) => Box::new(evaluator),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "two-plus-two"))]
+fn create_evaluator() -> Box<dyn Evaluator> {
+    default_evaluator()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BotKind {
+    TightAggressive,
+    CallingStation,
+    Random,
+}
+
+struct Options {
+    config: GameConfig,
+    /// The number of seats, counting back from the last, played by bots.
+    bots: usize,
+    bot_kind: BotKind,
+}
+
+fn create_bot(
+    kind: BotKind,
+    evaluator: &Arc<dyn Evaluator>,
+    seed: Option<u64>,
+) -> Box<dyn PlayerDriver> {
+    match (kind, seed) {
+        (BotKind::TightAggressive, Some(seed)) => {
+            Box::new(TightAggressive::new(evaluator.clone()).seed(seed))
+        }
+        (BotKind::TightAggressive, None) => Box::new(TightAggressive::new(evaluator.clone())),
+        (BotKind::CallingStation, _) => Box::new(CallingStation::new()),
+        (BotKind::Random, Some(seed)) => Box::new(RandomBot::new().seed(seed)),
+        (BotKind::Random, None) => Box::new(RandomBot::new()),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut builder = GameConfig::builder().players(3);
+    let mut bots = 0;
+    let mut bot_kind = BotKind::TightAggressive;
+    while let Some(arg) = args.next() {
+        if arg == "--help" {
+            println!("{}", USAGE);
+            process::exit(0);
+        }
+        if arg == "--secure-rng" {
+            builder = builder.secure_rng();
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", arg))?;
+        match arg.as_str() {
+            "--bots" => bots = parse_number(&arg, &value)?,
+            "--bot-kind" => {
+                bot_kind = match value.as_str() {
+                    "tight-aggressive" => BotKind::TightAggressive,
+                    "calling-station" => BotKind::CallingStation,
+                    "random" => BotKind::Random,
+                    _ => return Err(format!("Unknown bot kind {}", value)),
+                }
+            }
+            _ => builder = parse_option(builder, &arg, &value)?,
+        }
+    }
+    let config = builder.build()?;
+    let players = config.starting_stacks.len();
+    if bots > players {
+        return Err(format!(
+            "Cannot seat {} bots at a table of {} players",
+            bots, players
+        ));
+    }
+    Ok(Options {
+        config,
+        bots,
+        bot_kind,
+    })
+}
+
+fn parse_option(
+    builder: GameConfigBuilder,
+    option: &str,
+    value: &str,
+) -> Result<GameConfigBuilder, String> {
+    let builder = match option {
+        "--players" => builder.players(parse_number(option, value)?),
+        "--stack" => builder.starting_stack(parse_number(option, value)?),
+        "--stacks" => builder.starting_stacks(
+            value
+                .split(',')
+                .map(|stack| parse_number(option, stack))
+                .collect::<Result<Vec<u32>, String>>()?,
+        ),
+        "--blinds" => {
+            let (small_blind, big_blind) = value
+                .split_once('/')
+                .ok_or_else(|| format!("Expected SMALL/BIG for {}, found {}", option, value))?;
+            builder.blinds(
+                parse_number(option, small_blind)?,
+                parse_number(option, big_blind)?,
+            )
+        }
+        "--ante" => builder.ante(parse_number(option, value)?),
+        "--big-blind-ante" => builder.big_blind_ante(parse_number(option, value)?),
+        "--betting" => builder.betting_structure(match value {
+            "no-limit" => BettingStructure::NoLimit,
+            "pot-limit" => BettingStructure::PotLimit,
+            "fixed-limit" => BettingStructure::FixedLimit,
+            _ => return Err(format!("Unknown betting structure {}", value)),
+        }),
+        "--max-seats" => builder.max_seats(parse_number(option, value)?),
+        "--seed" => builder.seed(parse_number(option, value)?),
+        "--names" => builder.player_names(value.split(',').map(str::to_string).collect()),
+        _ => return Err(format!("Unknown option {}", option)),
+    };
+    Ok(builder)
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid number for {}: {}", option, value))
 }
 
 fn finish_game(finished: Finished) -> PreRound {
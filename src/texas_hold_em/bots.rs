@@ -0,0 +1,169 @@
+//! Drivers that choose moves on their own, to fill seats and play games unattended.
+
+pub mod calling_station;
+pub mod random_bot;
+pub mod tight_aggressive;
+
+use super::{
+    player_driver::{LegalAction, Move},
+    player_view::PlayerView,
+};
+
+/// Bets or raises to `amount` clamped to the allowed sizes, or calls or checks if the player
+/// cannot bet.
+fn bet_or_raise_to(view: &PlayerView, amount: u32) -> Move {
+    view.legal_actions
+        .iter()
+        .find_map(|action| match action {
+            LegalAction::Bet { min, max } => Some(Move::Bet {
+                amount: amount.clamp(*min, *max),
+            }),
+            LegalAction::RaiseTo { min, max } => Some(Move::RaiseTo {
+                amount: amount.clamp(*min, *max),
+            }),
+            _ => None,
+        })
+        .unwrap_or_else(|| check_or_call(view))
+}
+
+/// Checks or calls, going all in when the player cannot cover the call.
+fn check_or_call(view: &PlayerView) -> Move {
+    view.legal_actions
+        .iter()
+        .find_map(|action| match action {
+            LegalAction::Check => Some(Move::Check),
+            LegalAction::Call { .. } => Some(Move::Call),
+            _ => None,
+        })
+        .or_else(|| {
+            view.legal_actions
+                .iter()
+                .any(|action| matches!(action, LegalAction::AllIn { .. }))
+                .then_some(Move::AllIn)
+        })
+        .unwrap_or(Move::Fold)
+}
+
+/// Checks if there is nothing to call, otherwise folds.
+fn check_or_fold(view: &PlayerView) -> Move {
+    match view.legal_actions.contains(&LegalAction::Check) {
+        true => Move::Check,
+        false => Move::Fold,
+    }
+}
+
+/// A view of a player holding `hand` on `board`, for testing bots.
+#[cfg(test)]
+fn test_view(hand: &str, board: &str, legal_actions: Vec<LegalAction>) -> PlayerView {
+    use crate::{player::PlayerId, poker::card::parse_cards};
+
+    use super::player_view::OpponentView;
+
+    PlayerView {
+        player_id: PlayerId(1),
+        hand: hand.parse().unwrap(),
+        chips: 1000,
+        chips_bet_in_stage: 0,
+        community_cards: parse_cards(board).unwrap(),
+        opponents: vec![OpponentView {
+            id: PlayerId(2),
+            chips: 1000,
+            chips_bet_in_stage: 0,
+            folded: false,
+        }],
+        pot: 100,
+        minimum_bet: 0,
+        button: Some(PlayerId(1)),
+        legal_actions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::texas_hold_em::{
+        evaluation::bitmask_evaluator::BitmaskEvaluator,
+        game_config::GameConfig,
+        player_driver::{LegalAction, Move, PlayerDriver, PlayerDrivers},
+        state::advancement::Advancement,
+        Finished, PreFlop, PreRound,
+    };
+
+    use super::{
+        bet_or_raise_to, calling_station::CallingStation, check_or_call, random_bot::RandomBot,
+        test_view, tight_aggressive::TightAggressive,
+    };
+
+    #[test]
+    fn clamps_bets_to_legal_sizes() {
+        let view = test_view(
+            "AsKs",
+            "",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: 10 },
+                LegalAction::RaiseTo { min: 20, max: 500 },
+            ],
+        );
+        assert_eq!(Move::RaiseTo { amount: 20 }, bet_or_raise_to(&view, 5));
+        assert_eq!(Move::RaiseTo { amount: 500 }, bet_or_raise_to(&view, 800));
+    }
+
+    #[test]
+    fn goes_all_in_when_short_of_a_call() {
+        let view = test_view(
+            "AsKs",
+            "",
+            vec![LegalAction::Fold, LegalAction::AllIn { amount: 5 }],
+        );
+        assert_eq!(Move::AllIn, check_or_call(&view));
+        assert_eq!(Move::AllIn, bet_or_raise_to(&view, 100));
+    }
+
+    fn play_round(pre_flop: PreFlop, drivers: &mut PlayerDrivers) -> Finished {
+        let flop = match pre_flop.advance(drivers) {
+            Advancement::NextStage(stage) => stage,
+            Advancement::Finished(finished) => return *finished,
+        };
+        let turn = match flop.advance(drivers) {
+            Advancement::NextStage(stage) => stage,
+            Advancement::Finished(finished) => return *finished,
+        };
+        let river = match turn.advance(drivers) {
+            Advancement::NextStage(stage) => stage,
+            Advancement::Finished(finished) => return *finished,
+        };
+        match river.advance(drivers) {
+            Advancement::NextStage(showdown) => showdown.finish(&BitmaskEvaluator),
+            Advancement::Finished(finished) => *finished,
+        }
+    }
+
+    #[test]
+    fn bots_fill_a_table_unattended() {
+        let mut pre_round =
+            PreRound::new(GameConfig::builder().players(3).seed(7).build().unwrap());
+        let total_chips: u32 = pre_round.players.iter().map(|player| player.chips).sum();
+        let bots: Vec<Box<dyn PlayerDriver>> = vec![
+            Box::new(RandomBot::new().seed(1)),
+            Box::new(CallingStation::new()),
+            Box::new(TightAggressive::new(Arc::new(BitmaskEvaluator)).seed(3)),
+        ];
+        let mut drivers: PlayerDrivers = pre_round
+            .players
+            .iter()
+            .map(|player| player.id)
+            .zip(bots)
+            .collect();
+
+        for _ in 0..50 {
+            if pre_round.is_game_over() {
+                break;
+            }
+            pre_round = play_round(pre_round.start_round(), &mut drivers).payout();
+            let chips: u32 = pre_round.players.iter().map(|player| player.chips).sum();
+            assert_eq!(total_chips, chips);
+        }
+    }
+}
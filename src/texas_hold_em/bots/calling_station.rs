@@ -0,0 +1,80 @@
+use crate::texas_hold_em::{
+    player_driver::{Move, PlayerDriver},
+    player_view::PlayerView,
+};
+
+use super::{check_or_call, check_or_fold};
+
+/// Never bets or raises, and calls any bet up to an optional limit.
+pub struct CallingStation {
+    max_call: Option<u32>,
+}
+
+impl CallingStation {
+    pub fn new() -> Self {
+        CallingStation { max_call: None }
+    }
+
+    /// Folds rather than call more than this many chips.
+    pub fn max_call(mut self, chips: u32) -> Self {
+        self.max_call = Some(chips);
+        self
+    }
+}
+
+impl Default for CallingStation {
+    fn default() -> Self {
+        CallingStation::new()
+    }
+}
+
+impl PlayerDriver for CallingStation {
+    fn determine_move(&mut self, view: &PlayerView) -> Move {
+        match self.max_call {
+            Some(max_call) if view.amount_to_call().min(view.chips) > max_call => {
+                check_or_fold(view)
+            }
+            _ => check_or_call(view),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::texas_hold_em::{
+        bots::test_view,
+        player_driver::{LegalAction, Move, PlayerDriver},
+    };
+
+    use super::CallingStation;
+
+    #[rstest]
+    #[case(vec![LegalAction::Fold, LegalAction::Check, LegalAction::Bet { min: 10, max: 1000 }], Move::Check)]
+    #[case(vec![LegalAction::Fold, LegalAction::Call { amount: 10 }, LegalAction::RaiseTo { min: 20, max: 1000 }], Move::Call)]
+    #[case(vec![LegalAction::Fold, LegalAction::AllIn { amount: 1000 }], Move::AllIn)]
+    fn checks_and_calls(#[case] legal_actions: Vec<LegalAction>, #[case] expected_move: Move) {
+        let mut view = test_view("7c2d", "", legal_actions);
+        view.minimum_bet = 10;
+        assert_eq!(expected_move, CallingStation::new().determine_move(&view));
+    }
+
+    #[test]
+    fn folds_to_bets_above_limit() {
+        let mut view = test_view(
+            "7c2d",
+            "",
+            vec![LegalAction::Fold, LegalAction::Call { amount: 50 }],
+        );
+        view.minimum_bet = 50;
+        assert_eq!(
+            Move::Fold,
+            CallingStation::new().max_call(40).determine_move(&view)
+        );
+        assert_eq!(
+            Move::Call,
+            CallingStation::new().max_call(50).determine_move(&view)
+        );
+    }
+}
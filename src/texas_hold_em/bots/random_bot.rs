@@ -0,0 +1,133 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{RngSource, ShuffleRng};
+
+use crate::texas_hold_em::{
+    player_driver::{LegalAction, Move, PlayerDriver},
+    player_view::PlayerView,
+};
+
+/// Picks one of the legal actions at random, with a random size for bets and raises.
+///
+/// Useful for exercising every path through the betting rules.
+pub struct RandomBot {
+    rng: ShuffleRng,
+    free_folds: bool,
+}
+
+impl RandomBot {
+    pub fn new() -> Self {
+        RandomBot {
+            rng: ShuffleRng::new(RngSource::Entropy),
+            free_folds: false,
+        }
+    }
+
+    /// Chooses moves with a seeded generator, so a game can be replayed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = ShuffleRng::seeded(seed);
+        self
+    }
+
+    /// Whether to fold even when checking costs nothing. Off by default.
+    pub fn free_folds(mut self, free_folds: bool) -> Self {
+        self.free_folds = free_folds;
+        self
+    }
+}
+
+impl Default for RandomBot {
+    fn default() -> Self {
+        RandomBot::new()
+    }
+}
+
+impl PlayerDriver for RandomBot {
+    fn determine_move(&mut self, view: &PlayerView) -> Move {
+        let can_check = view.legal_actions.contains(&LegalAction::Check);
+        let actions: Vec<&LegalAction> = view
+            .legal_actions
+            .iter()
+            .filter(|action| self.free_folds || !can_check || **action != LegalAction::Fold)
+            .collect();
+        match actions.choose(&mut self.rng) {
+            Some(LegalAction::Check) => Move::Check,
+            Some(LegalAction::Call { .. }) => Move::Call,
+            Some(LegalAction::Bet { min, max }) => Move::Bet {
+                amount: self.rng.gen_range(*min..=*max),
+            },
+            Some(LegalAction::RaiseTo { min, max }) => Move::RaiseTo {
+                amount: self.rng.gen_range(*min..=*max),
+            },
+            Some(LegalAction::AllIn { .. }) => Move::AllIn,
+            Some(LegalAction::Fold) | None => Move::Fold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::texas_hold_em::{
+        bots::test_view,
+        player_driver::{LegalAction, Move, PlayerDriver},
+    };
+
+    use super::RandomBot;
+
+    #[test]
+    fn only_makes_legal_moves() {
+        let view = test_view(
+            "7c2d",
+            "",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: 10 },
+                LegalAction::RaiseTo { min: 20, max: 1000 },
+                LegalAction::AllIn { amount: 1000 },
+            ],
+        );
+        let mut bot = RandomBot::new().seed(4);
+        let mut folded = false;
+        for _ in 0..100 {
+            let player_move = bot.determine_move(&view);
+            folded |= player_move == Move::Fold;
+            assert!(view
+                .legal_actions
+                .iter()
+                .any(|action| action.validate(&player_move) == Some(Ok(()))));
+        }
+        assert!(folded);
+    }
+
+    #[test]
+    fn does_not_fold_when_it_can_check() {
+        let view = test_view(
+            "7c2d",
+            "",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Bet { min: 10, max: 1000 },
+            ],
+        );
+        let mut bot = RandomBot::new().seed(5);
+        assert!((0..100).all(|_| bot.determine_move(&view) != Move::Fold));
+
+        let mut bot = RandomBot::new().seed(5).free_folds(true);
+        assert!((0..100).any(|_| bot.determine_move(&view) == Move::Fold));
+    }
+
+    #[test]
+    fn same_seed_makes_the_same_moves() {
+        let view = test_view(
+            "7c2d",
+            "",
+            vec![LegalAction::Check, LegalAction::Bet { min: 10, max: 1000 }],
+        );
+        let mut first = RandomBot::new().seed(6);
+        let mut second = RandomBot::new().seed(6);
+        for _ in 0..20 {
+            assert_eq!(first.determine_move(&view), second.determine_move(&view));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::sync::Arc;
+
+use crate::{
+    texas_hold_em::{
+        equity::EquityCalculator,
+        evaluation::evaluator::Evaluator,
+        player_driver::{Move, PlayerDriver},
+        player_view::PlayerView,
+        range::Range,
+    },
+    CardSet,
+};
+
+use super::{bet_or_raise_to, check_or_call, check_or_fold};
+
+/// Settings for a [`TightAggressive`] bot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TightAggressiveConfig {
+    /// Hands to raise with before the flop, even facing a raise.
+    pub raise_range: Range,
+    /// Hands to call with before the flop, as long as the call is cheap.
+    pub call_range: Range,
+    /// The largest share of its chips, from 0 to 1, the bot calls with before the flop.
+    pub max_call_fraction: f64,
+    /// Raises before the flop to this many times the current bet.
+    pub raise_multiple: u32,
+    /// Bets or raises after the flop with at least this equity in percent.
+    pub value_equity: f64,
+    /// Bets this share of the pot after the flop, from 0 to 1.
+    pub bet_fraction: f64,
+    /// The number of boards sampled to work out equity after the flop.
+    pub iterations: u64,
+}
+
+impl Default for TightAggressiveConfig {
+    fn default() -> Self {
+        TightAggressiveConfig {
+            raise_range: "TT+, AQs+, AKo".parse().expect("Range should parse"),
+            call_range: "22+, A2s+, KTs+, QTs+, JTs, T9s, 98s, ATo+, KJo+, QJo"
+                .parse()
+                .expect("Range should parse"),
+            max_call_fraction: 0.1,
+            raise_multiple: 3,
+            value_equity: 65.0,
+            bet_fraction: 0.75,
+            iterations: 2_000,
+        }
+    }
+}
+
+/// Plays few hands before the flop, chosen from fixed ranges, and bets them hard.
+///
+/// After the flop it estimates its equity against the opponents still in the hand holding random
+/// hands, betting strong hands and calling when the pot odds are good enough.
+pub struct TightAggressive {
+    evaluator: Arc<dyn Evaluator>,
+    config: TightAggressiveConfig,
+    seed: Option<u64>,
+    decisions: u64,
+}
+
+impl TightAggressive {
+    pub fn new(evaluator: Arc<dyn Evaluator>) -> Self {
+        TightAggressive {
+            evaluator,
+            config: TightAggressiveConfig::default(),
+            seed: None,
+            decisions: 0,
+        }
+    }
+
+    pub fn config(mut self, config: TightAggressiveConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Samples boards with seeded generators, so a game can be replayed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    fn pre_flop_move(&self, view: &PlayerView) -> Move {
+        let config = &self.config;
+        if config.raise_range.contains(&view.hand) {
+            return bet_or_raise_to(view, view.minimum_bet * config.raise_multiple);
+        }
+        let max_call = (f64::from(view.chips) * config.max_call_fraction) as u32;
+        match config.call_range.contains(&view.hand) && view.amount_to_call() <= max_call {
+            true => check_or_call(view),
+            false => check_or_fold(view),
+        }
+    }
+
+    fn post_flop_move(&mut self, view: &PlayerView) -> Move {
+        let equity = self.equity(view);
+        let to_call = view.amount_to_call();
+        let bet = f64::from(view.pot + to_call) * self.config.bet_fraction;
+        if equity >= self.config.value_equity {
+            return bet_or_raise_to(view, view.minimum_bet + bet as u32);
+        }
+        // The share of the final pot the player has to put in to call
+        let pot_odds = f64::from(to_call) * 100.0 / f64::from(view.pot + to_call).max(1.0);
+        match equity >= pot_odds {
+            true => check_or_call(view),
+            false => check_or_fold(view),
+        }
+    }
+
+    /// The hand's equity in percent against the opponents still in the hand.
+    fn equity(&mut self, view: &PlayerView) -> f64 {
+        let opponents = view
+            .opponents
+            .iter()
+            .filter(|opponent| !opponent.folded)
+            .count()
+            .max(1);
+        let mut calculator = EquityCalculator::new(self.evaluator.as_ref())
+            .iterations(self.config.iterations)
+            .threads(1);
+        if let Some(seed) = self.seed {
+            calculator = calculator.seed(seed.wrapping_add(self.decisions));
+        }
+        self.decisions += 1;
+        calculator
+            .calculate_against_random(&view.hand, opponents, &view.community_cards, CardSet::new())
+            .map_or(0.0, |equity| equity.equity())
+    }
+}
+
+impl PlayerDriver for TightAggressive {
+    fn determine_move(&mut self, view: &PlayerView) -> Move {
+        match view.community_cards.is_empty() {
+            true => self.pre_flop_move(view),
+            false => self.post_flop_move(view),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rstest::rstest;
+
+    use crate::texas_hold_em::{
+        bots::test_view,
+        evaluation::bitmask_evaluator::BitmaskEvaluator,
+        player_driver::{LegalAction, Move, PlayerDriver},
+    };
+
+    use super::{TightAggressive, TightAggressiveConfig};
+
+    fn bot() -> TightAggressive {
+        TightAggressive::new(Arc::new(BitmaskEvaluator)).seed(9)
+    }
+
+    fn facing_bet(hand: &str, board: &str, bet: u32) -> Move {
+        let mut view = test_view(
+            hand,
+            board,
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: bet },
+                LegalAction::RaiseTo {
+                    min: bet * 2,
+                    max: 1000,
+                },
+            ],
+        );
+        view.minimum_bet = bet;
+        bot().determine_move(&view)
+    }
+
+    #[rstest]
+    #[case("AsAh", 10, Move::RaiseTo { amount: 30 })]
+    #[case("AhKh", 100, Move::RaiseTo { amount: 300 })]
+    #[case("5s5h", 10, Move::Call)]
+    #[case("5s5h", 200, Move::Fold)]
+    #[case("7c2d", 10, Move::Fold)]
+    fn plays_preflop_chart(#[case] hand: &str, #[case] bet: u32, #[case] expected_move: Move) {
+        assert_eq!(expected_move, facing_bet(hand, "", bet));
+    }
+
+    #[test]
+    fn checks_weak_hands_when_free() {
+        let view = test_view(
+            "7c2d",
+            "",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::RaiseTo { min: 20, max: 1000 },
+            ],
+        );
+        assert_eq!(Move::Check, bot().determine_move(&view));
+    }
+
+    #[test]
+    fn bets_strong_hands_after_the_flop() {
+        let view = test_view(
+            "AsKs",
+            "Qs Js Ts 2d 3c",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Bet { min: 10, max: 1000 },
+            ],
+        );
+        assert_eq!(Move::Bet { amount: 75 }, bot().determine_move(&view));
+    }
+
+    #[rstest]
+    #[case("5h4h", "As Kd 3c", 10, Move::Call)]
+    #[case("7c2d", "As Ks Qd 9h 4s", 100, Move::Fold)]
+    fn calls_with_pot_odds(
+        #[case] hand: &str,
+        #[case] board: &str,
+        #[case] bet: u32,
+        #[case] expected_move: Move,
+    ) {
+        assert_eq!(expected_move, facing_bet(hand, board, bet));
+    }
+
+    #[test]
+    fn uses_configured_ranges() {
+        let config = TightAggressiveConfig {
+            raise_range: "22+".parse().unwrap(),
+            ..TightAggressiveConfig::default()
+        };
+        let mut view = test_view(
+            "5s5h",
+            "",
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: 10 },
+                LegalAction::RaiseTo { min: 20, max: 1000 },
+            ],
+        );
+        view.minimum_bet = 10;
+        assert_eq!(
+            Move::RaiseTo { amount: 30 },
+            bot().config(config).determine_move(&view)
+        );
+    }
+}
@@ -1,30 +1,77 @@
 use std::io;
 
 use super::{
-    player_driver::{Move, PlayerDriver},
-    state::game_loop::GameLoop,
+    player_driver::{IllegalMoveError, LegalAction, Move, PlayerDriver},
+    player_view::PlayerView,
 };
 
 pub struct ConsolePlayer {}
 
 impl PlayerDriver for ConsolePlayer {
-    fn determine_move(game: &GameLoop) -> Move {
-        println!("Press F to fold, C to call, R to raise by 10");
+    fn illegal_move(&mut self, _player_move: Move, error: IllegalMoveError) {
+        println!("Illegal move: {}. Try again", error);
+    }
+
+    fn determine_move(&mut self, view: &PlayerView) -> Move {
+        println!(
+            "Player {} - Hand: {} - Chips: {} - To call: {}",
+            view.player_id,
+            view.hand,
+            view.chips,
+            view.amount_to_call()
+        );
+        println!(
+            "Legal actions: {}",
+            view.legal_actions
+                .iter()
+                .map(|action| action.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        println!("Press F to fold, X to check, C to call, B <amount> to bet, R <amount> to raise to, A to go all in");
+
+        loop {
+            let mut answer = String::new();
+            io::stdin()
+                .read_line(&mut answer)
+                .expect("Failed to read line");
 
-        let mut answer = String::new();
-        io::stdin()
-            .read_line(&mut answer)
-            .expect("Failed to read line");
+            match ConsolePlayer::parse_move(&answer, view) {
+                Some(player_move) => return player_move,
+                None => println!("Could not understand {}", answer.trim()),
+            }
+        }
+    }
+}
 
-        let player_move = match answer.to_ascii_lowercase().trim() {
+impl ConsolePlayer {
+    fn parse_move(answer: &str, view: &PlayerView) -> Option<Move> {
+        let answer = answer.to_ascii_lowercase();
+        let mut words = answer.split_whitespace();
+        let action = words.next()?;
+        let amount = words.next().and_then(|amount| amount.parse::<u32>().ok());
+
+        let player_move = match action {
             "f" => Move::Fold,
+            "x" => Move::Check,
             "c" => Move::Call,
-            "r" => Move::Raise {
-                amount: game.pot.minimum_bet + 10,
+            "b" => Move::Bet {
+                amount: amount.or_else(|| ConsolePlayer::minimum_bet(view))?,
+            },
+            "r" => Move::RaiseTo {
+                amount: amount.or_else(|| ConsolePlayer::minimum_bet(view))?,
             },
-            _ => Move::Fold,
+            "a" => Move::AllIn,
+            _ => return None,
         };
 
-        player_move
+        Some(player_move)
+    }
+
+    fn minimum_bet(view: &PlayerView) -> Option<u32> {
+        view.legal_actions.iter().find_map(|action| match action {
+            LegalAction::Bet { min, .. } | LegalAction::RaiseTo { min, .. } => Some(*min),
+            _ => None,
+        })
     }
 }
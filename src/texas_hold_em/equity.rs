@@ -0,0 +1,863 @@
+//! Calculates how often each hand wins, ties or loses against the others.
+//!
+//! The rest of the board is enumerated exhaustively when there are few enough possible boards, and
+//! sampled at random otherwise. Ranges of hands are always sampled.
+
+use std::{fmt::Display, num::NonZeroUsize, thread};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Card, CardSet, Hand, RngSource, ShuffleRng};
+
+use super::{
+    evaluation::evaluator::{Evaluator, HandVal, PartialEvaluation},
+    range::Range,
+};
+
+/// Enumerates every board up to this many, which covers heads-up hands before the flop.
+const DEFAULT_EXHAUSTIVE_LIMIT: u64 = 2_000_000;
+const DEFAULT_ITERATIONS: u64 = 100_000;
+/// How many times to try dealing a hand from every range before giving up on the ranges.
+const MAX_DEAL_ATTEMPTS: usize = 1_000;
+
+/// Works out the equity of hands, using an evaluator to compare them.
+pub struct EquityCalculator<'e> {
+    evaluator: &'e dyn Evaluator,
+    iterations: u64,
+    exhaustive_limit: u64,
+    rng_source: RngSource,
+    threads: usize,
+}
+
+impl<'e> EquityCalculator<'e> {
+    pub fn new(evaluator: &'e dyn Evaluator) -> Self {
+        EquityCalculator {
+            evaluator,
+            iterations: DEFAULT_ITERATIONS,
+            exhaustive_limit: DEFAULT_EXHAUSTIVE_LIMIT,
+            rng_source: RngSource::default(),
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+
+    /// The number of boards to sample when there are too many to enumerate.
+    pub fn iterations(mut self, iterations: u64) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Enumerates every board when there are at most this many, otherwise samples them.
+    ///
+    /// A limit of zero always samples.
+    pub fn exhaustive_limit(mut self, limit: u64) -> Self {
+        self.exhaustive_limit = limit;
+        self
+    }
+
+    /// Samples boards with a seeded generator, so results can be reproduced with the same seed
+    /// and number of threads.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng_source = RngSource::Seeded(seed);
+        self
+    }
+
+    /// The number of threads to share the work between, at least one.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Calculates the equity of each hand, given the board so far and cards known to be out of
+    /// play.
+    pub fn calculate(
+        &self,
+        hands: &[Hand],
+        board: &[Card],
+        dead: CardSet,
+    ) -> Result<EquityResult, EquityError> {
+        if hands.len() < 2 {
+            return Err(EquityError::TooFewHands(hands.len()));
+        }
+        if board.len() > 5 {
+            return Err(EquityError::BoardTooLong(board.len()));
+        }
+        let mut used = dead;
+        for card in hands
+            .iter()
+            .flat_map(|hand| hand.cards)
+            .chain(board.iter().copied())
+        {
+            if !used.insert(card) {
+                return Err(EquityError::DuplicateCard(card));
+            }
+        }
+
+        let remaining: Vec<Card> = (!used).iter().collect();
+        let needed = 5 - board.len();
+        if remaining.len() < needed {
+            return Err(EquityError::NotEnoughCards {
+                needed,
+                remaining: remaining.len(),
+            });
+        }
+
+        let board = self
+            .evaluator
+            .add_cards(self.evaluator.start_evaluation(), board);
+        let boards = combinations(remaining.len() as u64, needed as u64);
+        let exhaustive = boards <= self.exhaustive_limit;
+        let tally = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|worker| {
+                    let remaining = &remaining;
+                    scope.spawn(move || match exhaustive {
+                        true => self.enumerate(hands, board, remaining, needed, worker),
+                        false => self.sample(hands, board, remaining, needed, worker),
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("Equity worker should not panic"))
+                .fold(Tally::new(hands.len()), Tally::merge)
+        });
+
+        Ok(tally.into_result(exhaustive))
+    }
+
+    /// Calculates the equity of each range against the others, by sampling a hand from each range
+    /// and then the rest of the board.
+    ///
+    /// Hands are sampled in proportion to their weights, leaving out hands that use a card on the
+    /// board or a dead card. A single hand can be compared with a range using [`Range::from`].
+    pub fn calculate_ranges(
+        &self,
+        ranges: &[Range],
+        board: &[Card],
+        dead: CardSet,
+    ) -> Result<EquityResult, EquityError> {
+        if ranges.len() < 2 {
+            return Err(EquityError::TooFewHands(ranges.len()));
+        }
+        if board.len() > 5 {
+            return Err(EquityError::BoardTooLong(board.len()));
+        }
+        let mut used = dead;
+        for card in board.iter() {
+            if !used.insert(*card) {
+                return Err(EquityError::DuplicateCard(*card));
+            }
+        }
+
+        let needed = 5 - board.len();
+        let remaining = 52 - used.len();
+        if remaining < ranges.len() * 2 + needed {
+            return Err(EquityError::NotEnoughCards {
+                needed: ranges.len() * 2 + needed,
+                remaining,
+            });
+        }
+        let ranges = ranges
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                SampledRange::new(&range.without(used)).ok_or(EquityError::EmptyRange(i))
+            })
+            .collect::<Result<Vec<SampledRange>, EquityError>>()?;
+
+        let board = self
+            .evaluator
+            .add_cards(self.evaluator.start_evaluation(), board);
+        let tally = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|worker| {
+                    let ranges = &ranges;
+                    scope.spawn(move || self.sample_ranges(ranges, board, used, needed, worker))
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("Equity worker should not panic"))
+                .try_fold(Tally::new(ranges.len()), |total, tally| {
+                    Ok(total.merge(tally?))
+                })
+        })?;
+
+        Ok(tally.into_result(false))
+    }
+
+    /// Calculates the equity of a hand against `opponents` players holding random hands, by
+    /// sampling their hands together with the rest of the board.
+    ///
+    /// This gives the same result as [`EquityCalculator::calculate_ranges`] against full ranges,
+    /// without having to retry deals whose hands share a card.
+    pub fn calculate_against_random(
+        &self,
+        hand: &Hand,
+        opponents: usize,
+        board: &[Card],
+        dead: CardSet,
+    ) -> Result<PlayerEquity, EquityError> {
+        if opponents == 0 {
+            return Err(EquityError::TooFewHands(1));
+        }
+        if board.len() > 5 {
+            return Err(EquityError::BoardTooLong(board.len()));
+        }
+        let mut used = dead;
+        for card in hand.cards.iter().chain(board.iter()) {
+            if !used.insert(*card) {
+                return Err(EquityError::DuplicateCard(*card));
+            }
+        }
+
+        let remaining: Vec<Card> = (!used).iter().collect();
+        let needed = 5 - board.len();
+        if remaining.len() < opponents * 2 + needed {
+            return Err(EquityError::NotEnoughCards {
+                needed: opponents * 2 + needed,
+                remaining: remaining.len(),
+            });
+        }
+
+        let board = self
+            .evaluator
+            .add_cards(self.evaluator.start_evaluation(), board);
+        let tally = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|worker| {
+                    let remaining = &remaining;
+                    scope.spawn(move || {
+                        self.sample_opponents(*hand, opponents, board, remaining, needed, worker)
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("Equity worker should not panic"))
+                .fold(Tally::new(opponents + 1), Tally::merge)
+        });
+
+        Ok(tally.into_result(false).players[0])
+    }
+
+    /// Scores every completion of the board whose first card is one of this worker's share.
+    fn enumerate(
+        &self,
+        hands: &[Hand],
+        board: PartialEvaluation,
+        remaining: &[Card],
+        needed: usize,
+        worker: usize,
+    ) -> Tally {
+        let mut tally = Tally::new(hands.len());
+        if needed == 0 {
+            if worker == 0 {
+                tally.score(self.evaluator, hands, board);
+            }
+            return tally;
+        }
+        for first in (worker..=remaining.len() - needed).step_by(self.threads) {
+            let board = self.evaluator.add_card(board, remaining[first]);
+            self.enumerate_from(
+                hands,
+                board,
+                &remaining[first + 1..],
+                needed - 1,
+                &mut tally,
+            );
+        }
+        tally
+    }
+
+    fn enumerate_from(
+        &self,
+        hands: &[Hand],
+        board: PartialEvaluation,
+        remaining: &[Card],
+        needed: usize,
+        tally: &mut Tally,
+    ) {
+        if needed == 0 {
+            tally.score(self.evaluator, hands, board);
+            return;
+        }
+        for next in 0..=remaining.len() - needed {
+            let board = self.evaluator.add_card(board, remaining[next]);
+            self.enumerate_from(hands, board, &remaining[next + 1..], needed - 1, tally);
+        }
+    }
+
+    /// Scores this worker's share of randomly completed boards.
+    fn sample(
+        &self,
+        hands: &[Hand],
+        board: PartialEvaluation,
+        remaining: &[Card],
+        needed: usize,
+        worker: usize,
+    ) -> Tally {
+        let mut rng = self.worker_rng(worker);
+        let mut remaining = remaining.to_vec();
+        let mut tally = Tally::new(hands.len());
+        for _ in 0..self.worker_iterations(worker) {
+            let (cards, _) = remaining.partial_shuffle(&mut rng, needed);
+            let board = self.evaluator.add_cards(board, cards);
+            tally.score(self.evaluator, hands, board);
+        }
+        tally
+    }
+
+    /// Scores this worker's share of boards, each with a hand dealt from every range.
+    fn sample_ranges(
+        &self,
+        ranges: &[SampledRange],
+        board: PartialEvaluation,
+        used: CardSet,
+        needed: usize,
+        worker: usize,
+    ) -> Result<Tally, EquityError> {
+        let mut rng = self.worker_rng(worker);
+        let mut hands = Vec::with_capacity(ranges.len());
+        let mut tally = Tally::new(ranges.len());
+        for _ in 0..self.worker_iterations(worker) {
+            let mut dealt = (0..MAX_DEAL_ATTEMPTS)
+                .find_map(|_| deal_from_ranges(ranges, used, &mut rng, &mut hands))
+                .ok_or(EquityError::NoCompatibleHands)?;
+            let mut board = board;
+            for _ in 0..needed {
+                let card = loop {
+                    let card = Card::from_index(rng.gen_range(0..52))
+                        .expect("Index below 52 should be a card");
+                    if dealt.insert(card) {
+                        break card;
+                    }
+                };
+                board = self.evaluator.add_card(board, card);
+            }
+            tally.score(self.evaluator, &hands, board);
+        }
+        Ok(tally)
+    }
+
+    /// Scores this worker's share of boards, each with random hands dealt to the opponents.
+    fn sample_opponents(
+        &self,
+        hand: Hand,
+        opponents: usize,
+        board: PartialEvaluation,
+        remaining: &[Card],
+        needed: usize,
+        worker: usize,
+    ) -> Tally {
+        let mut rng = self.worker_rng(worker);
+        let mut remaining = remaining.to_vec();
+        let mut hands = Vec::with_capacity(opponents + 1);
+        let mut tally = Tally::new(opponents + 1);
+        for _ in 0..self.worker_iterations(worker) {
+            let (cards, _) = remaining.partial_shuffle(&mut rng, opponents * 2 + needed);
+            let (hole_cards, board_cards) = cards.split_at(opponents * 2);
+            hands.clear();
+            hands.push(hand);
+            hands.extend(
+                hole_cards
+                    .chunks_exact(2)
+                    .map(|cards| Hand::new([cards[0], cards[1]])),
+            );
+            let board = self.evaluator.add_cards(board, board_cards);
+            tally.score(self.evaluator, &hands, board);
+        }
+        tally
+    }
+
+    /// Each worker has its own generator, seeded differently when a seed is set.
+    fn worker_rng(&self, worker: usize) -> ShuffleRng {
+        match self.rng_source {
+            RngSource::Seeded(seed) => ShuffleRng::seeded(seed.wrapping_add(worker as u64)),
+            source => ShuffleRng::new(source),
+        }
+    }
+
+    fn worker_iterations(&self, worker: usize) -> u64 {
+        let threads = self.threads as u64;
+        self.iterations / threads + u64::from((worker as u64) < self.iterations % threads)
+    }
+}
+
+/// The hands in a range, for picking one in proportion to its weight.
+struct SampledRange {
+    hands: Vec<Hand>,
+    /// The total weight of each hand and the hands before it.
+    cumulative_weights: Vec<f64>,
+}
+
+impl SampledRange {
+    /// Returns `None` if no hand in the range has any weight.
+    fn new(range: &Range) -> Option<Self> {
+        let combos: Vec<_> = range
+            .combos()
+            .iter()
+            .filter(|combo| combo.weight > 0.0)
+            .collect();
+        if combos.is_empty() {
+            return None;
+        }
+        let cumulative_weights = combos
+            .iter()
+            .scan(0.0, |total, combo| {
+                *total += combo.weight;
+                Some(*total)
+            })
+            .collect();
+        Some(SampledRange {
+            hands: combos.iter().map(|combo| combo.hand).collect(),
+            cumulative_weights,
+        })
+    }
+
+    fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> Hand {
+        let total = self.cumulative_weights[self.cumulative_weights.len() - 1];
+        let target = rng.gen::<f64>() * total;
+        let index = self
+            .cumulative_weights
+            .partition_point(|&weight| weight <= target);
+        self.hands[index.min(self.hands.len() - 1)]
+    }
+}
+
+/// Deals a hand from each range into `hands`, returning every card in use, or `None` if the
+/// hands picked share a card.
+fn deal_from_ranges<R: Rng + ?Sized>(
+    ranges: &[SampledRange],
+    used: CardSet,
+    rng: &mut R,
+    hands: &mut Vec<Hand>,
+) -> Option<CardSet> {
+    hands.clear();
+    let mut dealt = used;
+    for range in ranges {
+        let hand = range.pick(rng);
+        let cards = CardSet::from(hand);
+        if !dealt.is_disjoint(cards) {
+            return None;
+        }
+        dealt |= cards;
+        hands.push(hand);
+    }
+    Some(dealt)
+}
+
+/// Outcomes counted over the boards a worker has scored.
+struct Tally {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    pot_shares: Vec<f64>,
+    boards: u64,
+}
+
+impl Tally {
+    fn new(hands: usize) -> Self {
+        Tally {
+            wins: vec![0; hands],
+            ties: vec![0; hands],
+            pot_shares: vec![0.0; hands],
+            boards: 0,
+        }
+    }
+
+    fn score(&mut self, evaluator: &dyn Evaluator, hands: &[Hand], board: PartialEvaluation) {
+        let values: Vec<HandVal> = hands
+            .iter()
+            .map(|hand| {
+                evaluator
+                    .finish_evaluation(evaluator.add_cards(board, &hand.cards))
+                    .expect("Hand and board should make seven cards")
+            })
+            .collect();
+        let best = values.iter().max().copied();
+        let winners = values.iter().filter(|value| Some(**value) == best).count();
+        for (i, value) in values.iter().enumerate() {
+            if Some(*value) == best {
+                match winners {
+                    1 => self.wins[i] += 1,
+                    _ => self.ties[i] += 1,
+                }
+                self.pot_shares[i] += 1.0 / winners as f64;
+            }
+        }
+        self.boards += 1;
+    }
+
+    fn merge(mut self, other: Tally) -> Self {
+        for i in 0..self.wins.len() {
+            self.wins[i] += other.wins[i];
+            self.ties[i] += other.ties[i];
+            self.pot_shares[i] += other.pot_shares[i];
+        }
+        self.boards += other.boards;
+        self
+    }
+
+    fn into_result(self, exhaustive: bool) -> EquityResult {
+        let players = (0..self.wins.len())
+            .map(|i| PlayerEquity {
+                wins: self.wins[i],
+                ties: self.ties[i],
+                losses: self.boards - self.wins[i] - self.ties[i],
+                pot_share: self.pot_shares[i],
+                boards: self.boards,
+            })
+            .collect();
+        EquityResult {
+            players,
+            boards: self.boards,
+            exhaustive,
+        }
+    }
+}
+
+/// The outcome for each hand or range, in the order they were given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityResult {
+    pub players: Vec<PlayerEquity>,
+    /// The number of boards scored.
+    pub boards: u64,
+    /// Whether every possible board was scored, rather than a random sample.
+    pub exhaustive: bool,
+}
+
+/// How often a player's hand won, tied or lost.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerEquity {
+    pub wins: u64,
+    pub ties: u64,
+    pub losses: u64,
+    /// The number of pots won, counting a pot split between two hands as a half.
+    pub pot_share: f64,
+    pub boards: u64,
+}
+
+impl PlayerEquity {
+    pub fn win_percentage(&self) -> f64 {
+        self.percentage(self.wins as f64)
+    }
+
+    pub fn tie_percentage(&self) -> f64 {
+        self.percentage(self.ties as f64)
+    }
+
+    pub fn loss_percentage(&self) -> f64 {
+        self.percentage(self.losses as f64)
+    }
+
+    /// The percentage of the pot the hand wins on average.
+    pub fn equity(&self) -> f64 {
+        self.percentage(self.pot_share)
+    }
+
+    fn percentage(&self, count: f64) -> f64 {
+        match self.boards {
+            0 => 0.0,
+            boards => count * 100.0 / boards as f64,
+        }
+    }
+}
+
+impl Display for PlayerEquity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Equity: {:.2}% - Win: {:.2}% - Tie: {:.2}%",
+            self.equity(),
+            self.win_percentage(),
+            self.tie_percentage()
+        )
+    }
+}
+
+/// Why equity could not be calculated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquityError {
+    TooFewHands(usize),
+    BoardTooLong(usize),
+    /// A card is in more than one hand, the board or the dead cards.
+    DuplicateCard(Card),
+    NotEnoughCards {
+        needed: usize,
+        remaining: usize,
+    },
+    /// The range at this index has no hands left once the board and dead cards are removed.
+    EmptyRange(usize),
+    /// Hands could not be dealt from every range without sharing a card.
+    NoCompatibleHands,
+}
+
+impl Display for EquityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquityError::TooFewHands(hands) => {
+                write!(f, "At least two hands are needed, but found {}", hands)
+            }
+            EquityError::BoardTooLong(cards) => {
+                write!(f, "The board has at most five cards, but found {}", cards)
+            }
+            EquityError::DuplicateCard(card) => write!(f, "{} is used more than once", card),
+            EquityError::NotEnoughCards { needed, remaining } => write!(
+                f,
+                "{} cards are needed to deal the hands and board, but only {} remain",
+                needed, remaining
+            ),
+            EquityError::EmptyRange(index) => {
+                write!(f, "Range {} has no hands left to deal", index + 1)
+            }
+            EquityError::NoCompatibleHands => {
+                write!(
+                    f,
+                    "Could not deal a hand from every range without sharing cards"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// The number of ways to choose `k` of `n` items.
+fn combinations(n: u64, k: u64) -> u64 {
+    (0..k).fold(1, |result, i| result * (n - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        poker::card::parse_cards,
+        texas_hold_em::{evaluation::bitmask_evaluator::BitmaskEvaluator, range::Range},
+        Card, CardSet, Hand,
+    };
+
+    use super::{combinations, EquityCalculator, EquityError, EquityResult};
+
+    fn hands(hands: &[&str]) -> Vec<Hand> {
+        hands.iter().map(|hand| hand.parse().unwrap()).collect()
+    }
+
+    fn board(board: &str) -> Vec<Card> {
+        parse_cards(board).unwrap()
+    }
+
+    #[test]
+    fn enumerates_every_river() {
+        let result = EquityCalculator::new(&BitmaskEvaluator)
+            .calculate(
+                &hands(&["AsKs", "QdQc"]),
+                &board("Qs Js 2h 3c"),
+                CardSet::new(),
+            )
+            .unwrap();
+
+        assert!(result.exhaustive);
+        assert_eq!(44, result.boards);
+        // Any ten, or a spade that does not pair the board
+        assert_eq!(10, result.players[0].wins);
+        assert_eq!(34, result.players[1].wins);
+        assert_eq!(0, result.players[0].ties);
+    }
+
+    #[test]
+    fn leaves_out_dead_cards() {
+        let result = EquityCalculator::new(&BitmaskEvaluator)
+            .calculate(
+                &hands(&["AsKs", "QdQc"]),
+                &board("Qs Js 2h 3c"),
+                "Ts Th".parse().unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(42, result.boards);
+        assert_eq!(8, result.players[0].wins);
+    }
+
+    #[test]
+    fn splits_tied_pots() {
+        let result = EquityCalculator::new(&BitmaskEvaluator)
+            .calculate(
+                &hands(&["2c3c", "2d3d"]),
+                &board("As Ks Qs Js Ts"),
+                CardSet::new(),
+            )
+            .unwrap();
+
+        assert_eq!(1, result.boards);
+        assert_eq!(100.0, result.players[0].tie_percentage());
+        assert_eq!(50.0, result.players[1].equity());
+    }
+
+    #[test]
+    fn results_do_not_depend_on_thread_count() {
+        let calculate = |threads| {
+            EquityCalculator::new(&BitmaskEvaluator)
+                .threads(threads)
+                .calculate(
+                    &hands(&["AhKh", "2c2d", "9s8s"]),
+                    &board("Qh Jh 3s"),
+                    CardSet::new(),
+                )
+                .unwrap()
+        };
+
+        let outcomes = |result: &EquityResult| -> Vec<(u64, u64, u64)> {
+            result
+                .players
+                .iter()
+                .map(|player| (player.wins, player.ties, player.losses))
+                .collect()
+        };
+        let single = calculate(1);
+        assert_eq!(combinations(43, 2), single.boards);
+        assert_eq!(outcomes(&single), outcomes(&calculate(3)));
+        let equity: f64 = single.players.iter().map(|player| player.equity()).sum();
+        assert!((equity - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn samples_boards_when_there_are_too_many() {
+        let calculate = |seed| {
+            EquityCalculator::new(&BitmaskEvaluator)
+                .exhaustive_limit(0)
+                .iterations(20_000)
+                .threads(2)
+                .seed(seed)
+                .calculate(&hands(&["AsAh", "KsKh"]), &[], CardSet::new())
+                .unwrap()
+        };
+
+        let result = calculate(1);
+        assert!(!result.exhaustive);
+        assert_eq!(20_000, result.boards);
+        // Aces are about 82% against kings
+        assert!((result.players[0].equity() - 82.0).abs() < 2.0);
+        assert_eq!(result, calculate(1));
+        assert_ne!(result, calculate(2));
+    }
+
+    #[test]
+    fn rejects_invalid_hands_and_boards() {
+        let calculator = EquityCalculator::new(&BitmaskEvaluator);
+        assert_eq!(
+            Err(EquityError::TooFewHands(1)),
+            calculator.calculate(&hands(&["AsAh"]), &[], CardSet::new())
+        );
+        assert_eq!(
+            Err(EquityError::DuplicateCard("As".parse().unwrap())),
+            calculator.calculate(&hands(&["AsAh", "KsKh"]), &board("As"), CardSet::new())
+        );
+        assert_eq!(
+            Err(EquityError::BoardTooLong(6)),
+            calculator.calculate(
+                &hands(&["AsAh", "KsKh"]),
+                &board("2c 3c 4c 5c 6c 7c"),
+                CardSet::new()
+            )
+        );
+    }
+
+    fn range(range: &str) -> Range {
+        range.parse().unwrap()
+    }
+
+    #[test]
+    fn calculates_hand_against_range() {
+        let result = EquityCalculator::new(&BitmaskEvaluator)
+            .iterations(2_000)
+            .seed(3)
+            .calculate_ranges(
+                &[Range::from(hands(&["AsKs"])[0]), range("QQ, JJ")],
+                &board("Qs Js 2h 3c"),
+                CardSet::new(),
+            )
+            .unwrap();
+
+        // Ten outs against either set, as when the hand is known
+        assert_eq!(2_000, result.boards);
+        assert!((result.players[0].win_percentage() - 100.0 * 10.0 / 44.0).abs() < 3.0);
+        assert_eq!(0, result.players[1].ties);
+    }
+
+    #[test]
+    fn calculates_range_against_range() {
+        let result = EquityCalculator::new(&BitmaskEvaluator)
+            .iterations(10_000)
+            .seed(5)
+            .calculate_ranges(&[range("AA"), range("KK, QQ")], &[], CardSet::new())
+            .unwrap();
+
+        // Aces are about 81-82% against either pair
+        assert!((result.players[0].equity() - 81.5).abs() < 2.0);
+        let equity: f64 = result.players.iter().map(|player| player.equity()).sum();
+        assert!((equity - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn samples_ranges_by_weight() {
+        let calculate = |weight: &str| {
+            EquityCalculator::new(&BitmaskEvaluator)
+                .iterations(4_000)
+                .seed(8)
+                .calculate_ranges(
+                    &[range("AsAh"), range(&format!("KsKh, 2c2d:{}", weight))],
+                    &board("Kd 7c 8h 9s"),
+                    CardSet::new(),
+                )
+                .unwrap()
+        };
+
+        // Kings have a set and deuces have nothing, so the weights decide how often aces win
+        assert!((calculate("1").players[0].win_percentage() - 50.0).abs() < 5.0);
+        assert!(calculate("0").players[0].win_percentage() < 5.0);
+    }
+
+    #[test]
+    fn calculates_hand_against_random_opponents() {
+        let calculator = EquityCalculator::new(&BitmaskEvaluator)
+            .iterations(20_000)
+            .seed(11);
+        let aces = hands(&["AsAh"])[0];
+
+        // Aces are about 85% against one random hand and 64% against three
+        let heads_up = calculator
+            .calculate_against_random(&aces, 1, &[], CardSet::new())
+            .unwrap();
+        assert_eq!(20_000, heads_up.boards);
+        assert!((heads_up.equity() - 85.2).abs() < 1.5);
+        let three_way = calculator
+            .calculate_against_random(&aces, 3, &[], CardSet::new())
+            .unwrap();
+        assert!((three_way.equity() - 63.9).abs() < 1.5);
+
+        assert_eq!(
+            Err(EquityError::TooFewHands(1)),
+            calculator.calculate_against_random(&aces, 0, &[], CardSet::new())
+        );
+        assert_eq!(
+            Err(EquityError::DuplicateCard("As".parse().unwrap())),
+            calculator.calculate_against_random(&aces, 1, &board("As"), CardSet::new())
+        );
+    }
+
+    #[test]
+    fn rejects_ranges_without_hands() {
+        let calculator = EquityCalculator::new(&BitmaskEvaluator).iterations(10);
+        assert_eq!(
+            Err(EquityError::EmptyRange(1)),
+            calculator.calculate_ranges(
+                &[range("AA"), range("KsKh")],
+                &board("Ks"),
+                CardSet::new()
+            )
+        );
+        assert_eq!(
+            Err(EquityError::NoCompatibleHands),
+            calculator.calculate_ranges(&[range("AsAh"), range("AsAh")], &[], CardSet::new())
+        );
+    }
+}
@@ -1,4 +1,10 @@
-mod database;
+mod bitmask;
+pub mod bitmask_evaluator;
+#[cfg(feature = "two-plus-two")]
+pub mod database;
 pub mod evaluator;
+pub mod hand_description;
 pub mod hand_rank;
+pub mod hand_ranks_generator;
+#[cfg(feature = "two-plus-two")]
 pub mod two_plus_two_evaluator;
@@ -0,0 +1,201 @@
+//! Evaluates hands from bitmasks of their ranks and suits, without any lookup tables.
+//!
+//! This module has no dependencies on the rest of the crate, so it can be used by the build script.
+
+/// Evaluates the best five card hand from five to seven `(rank, suit)` pairs.
+///
+/// Ranks run from 0 (two) to 12 (ace), and suits from 1 to 4, with 0 for cards that cannot be part
+/// of a flush. The value is the category (1 for high card up to 9 for straight flush) in the top
+/// bits, and the 1-based strength of the hand within its category in the bottom 12 bits.
+pub fn evaluate(cards: &[(u32, u32)]) -> u32 {
+    let mut rank_counts = [0u32; 13];
+    let mut suit_masks = [0u32; 5];
+    let mut rank_mask = 0;
+    for &(rank, suit) in cards {
+        rank_counts[rank as usize] += 1;
+        suit_masks[suit as usize] |= 1 << rank;
+        rank_mask |= 1 << rank;
+    }
+
+    let flush_mask = suit_masks[1..]
+        .iter()
+        .copied()
+        .find(|mask| mask.count_ones() >= 5);
+
+    if let Some(high) = flush_mask.and_then(straight_high) {
+        return value(STRAIGHT_FLUSH, high - 2);
+    }
+
+    let ranks_with = |count: u32| {
+        (0..13u32)
+            .rev()
+            .filter(move |&rank| rank_counts[rank as usize] >= count)
+    };
+
+    if let Some(quads) = ranks_with(4).next() {
+        let kicker = highest_ranks::<1>(rank_mask & !(1 << quads))[0];
+        return value(FOUR_OF_A_KIND, quads * 12 + below(kicker, &[quads]) + 1);
+    }
+
+    if let Some(trips) = ranks_with(3).next() {
+        if let Some(pair) = ranks_with(2).find(|&rank| rank != trips) {
+            return value(FULL_HOUSE, trips * 12 + below(pair, &[trips]) + 1);
+        }
+    }
+
+    if let Some(flush_mask) = flush_mask {
+        return value(FLUSH, distinct_ranks_index(highest_ranks(flush_mask)));
+    }
+
+    if let Some(high) = straight_high(rank_mask) {
+        return value(STRAIGHT, high - 2);
+    }
+
+    if let Some(trips) = ranks_with(3).next() {
+        let kickers = highest_ranks::<2>(rank_mask & !(1 << trips));
+        let kickers = kickers.map(|kicker| below(kicker, &[trips]));
+        let index = choose(kickers[0], 2) + choose(kickers[1], 1);
+        return value(THREE_OF_A_KIND, trips * 66 + index + 1);
+    }
+
+    let mut pairs = ranks_with(2);
+    if let Some(high_pair) = pairs.next() {
+        if let Some(low_pair) = pairs.next() {
+            let kicker = highest_ranks::<1>(rank_mask & !(1 << high_pair) & !(1 << low_pair))[0];
+            let pairs_index = choose(high_pair, 2) + choose(low_pair, 1);
+            let kicker_index = below(kicker, &[high_pair, low_pair]);
+            return value(TWO_PAIR, pairs_index * 11 + kicker_index + 1);
+        }
+
+        let kickers = highest_ranks::<3>(rank_mask & !(1 << high_pair));
+        let kickers = kickers.map(|kicker| below(kicker, &[high_pair]));
+        let index = choose(kickers[0], 3) + choose(kickers[1], 2) + choose(kickers[2], 1);
+        return value(PAIR, high_pair * 220 + index + 1);
+    }
+
+    value(HIGH_CARD, distinct_ranks_index(highest_ranks(rank_mask)))
+}
+
+const HIGH_CARD: u32 = 1;
+const PAIR: u32 = 2;
+const TWO_PAIR: u32 = 3;
+const THREE_OF_A_KIND: u32 = 4;
+const STRAIGHT: u32 = 5;
+const FLUSH: u32 = 6;
+const FULL_HOUSE: u32 = 7;
+const FOUR_OF_A_KIND: u32 = 8;
+const STRAIGHT_FLUSH: u32 = 9;
+
+const WHEEL: u32 = 0b1_0000_0000_1111;
+
+fn value(category: u32, index: u32) -> u32 {
+    (category << 12) + index
+}
+
+/// The rank of the highest card of the best straight in the mask, with a five-high straight as 3.
+fn straight_high(mask: u32) -> Option<u32> {
+    (4..13)
+        .rev()
+        .find(|&high| (mask >> (high - 4)) & 0b11111 == 0b11111)
+        .or(if mask & WHEEL == WHEEL { Some(3) } else { None })
+}
+
+/// The highest `N` ranks in the mask, in descending order.
+fn highest_ranks<const N: usize>(mut mask: u32) -> [u32; N] {
+    let mut ranks = [0; N];
+    for rank in ranks.iter_mut() {
+        *rank = 31 - mask.leading_zeros();
+        mask &= !(1 << *rank);
+    }
+    ranks
+}
+
+/// The rank's position among the ranks not already used by the hand.
+fn below(rank: u32, used: &[u32]) -> u32 {
+    rank - used.iter().filter(|&&used| used < rank).count() as u32
+}
+
+/// The 1-based strength of five distinct ranks that are not a straight.
+fn distinct_ranks_index(ranks: [u32; 5]) -> u32 {
+    let index = combination_index(ranks);
+    let straights_below = STRAIGHT_INDICES
+        .iter()
+        .filter(|&&straight| straight < index)
+        .count() as u32;
+    index - straights_below + 1
+}
+
+/// The position of a set of five descending ranks in the order of all such sets.
+const fn combination_index(ranks: [u32; 5]) -> u32 {
+    choose(ranks[0], 5)
+        + choose(ranks[1], 4)
+        + choose(ranks[2], 3)
+        + choose(ranks[3], 2)
+        + choose(ranks[4], 1)
+}
+
+const STRAIGHT_INDICES: [u32; 10] = {
+    let mut indices = [combination_index([12, 3, 2, 1, 0]); 10];
+    let mut high = 4;
+    while high < 13 {
+        indices[high as usize - 3] =
+            combination_index([high, high - 1, high - 2, high - 3, high - 4]);
+        high += 1;
+    }
+    indices
+};
+
+const fn choose(n: u32, k: u32) -> u32 {
+    if k > n {
+        return 0;
+    }
+    let mut result = 1;
+    let mut i = 0;
+    while i < k {
+        result = result * (n - i) / (i + 1);
+        i += 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::evaluate;
+
+    #[rstest]
+    #[case(&[(12, 1), (11, 1), (10, 1), (9, 1), (8, 1)], 9 << 12 | 10)]
+    #[case(&[(3, 1), (2, 1), (1, 1), (0, 1), (12, 1)], 9 << 12 | 1)]
+    #[case(&[(0, 1), (0, 2), (0, 3), (0, 4), (1, 1)], 8 << 12 | 1)]
+    #[case(&[(12, 1), (12, 2), (12, 3), (12, 4), (11, 1)], 8 << 12 | 156)]
+    #[case(&[(12, 1), (12, 2), (12, 3), (11, 4), (11, 1)], 7 << 12 | 156)]
+    #[case(&[(12, 1), (11, 1), (10, 1), (9, 1), (7, 1)], 6 << 12 | 1277)]
+    #[case(&[(5, 1), (3, 1), (2, 1), (1, 1), (0, 1)], 6 << 12 | 1)]
+    #[case(&[(12, 1), (11, 2), (10, 1), (9, 1), (8, 1)], 5 << 12 | 10)]
+    #[case(&[(12, 1), (12, 2), (12, 3), (11, 1), (10, 1)], 4 << 12 | 858)]
+    #[case(&[(12, 1), (12, 2), (11, 3), (11, 1), (10, 1)], 3 << 12 | 858)]
+    #[case(&[(1, 1), (1, 2), (0, 3), (0, 1), (2, 1)], 3 << 12 | 1)]
+    #[case(&[(12, 1), (12, 2), (11, 3), (10, 1), (9, 1)], 2 << 12 | 2860)]
+    #[case(&[(0, 1), (0, 2), (1, 3), (2, 1), (3, 1)], 2 << 12 | 1)]
+    #[case(&[(12, 1), (11, 2), (10, 3), (9, 1), (7, 1)], 1 << 12 | 1277)]
+    #[case(&[(5, 1), (3, 2), (2, 3), (1, 1), (0, 1)], 1 << 12 | 1)]
+    fn evaluates_five_cards(#[case] cards: &[(u32, u32)], #[case] expected: u32) {
+        assert_eq!(expected, evaluate(cards));
+    }
+
+    #[test]
+    fn evaluates_best_five_of_seven_cards() {
+        // Three pairs play the highest two, with the third pair as a possible kicker
+        let cards = [(10, 1), (10, 2), (8, 3), (8, 4), (9, 1), (9, 2), (0, 3)];
+        let best_five = [(10, 1), (10, 2), (9, 1), (9, 2), (8, 3)];
+        assert_eq!(evaluate(&best_five), evaluate(&cards));
+    }
+
+    #[test]
+    fn unsuited_cards_do_not_make_a_flush() {
+        let unsuited = [(12, 0), (10, 0), (8, 0), (6, 0), (4, 0)];
+        let offsuit = [(12, 1), (10, 2), (8, 3), (6, 4), (4, 1)];
+        assert_eq!(evaluate(&offsuit), evaluate(&unsuited));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::Card;
+
+use super::{
+    bitmask,
+    evaluator::{Evaluator, HandVal, PartialEvaluation},
+};
+
+/// Evaluates hands directly from their cards, so it needs no data files.
+///
+/// Gives the same values as the two plus two evaluator, but is slower.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BitmaskEvaluator;
+
+impl Evaluator for BitmaskEvaluator {
+    fn evaluate_hand(&self, cards: &[Card; 7]) -> HandVal {
+        let cards = cards.map(|card| (card.rank as u32, card.suit as u32 + 1));
+        HandVal(bitmask::evaluate(&cards))
+    }
+
+    fn finish_evaluation(&self, partial: PartialEvaluation) -> Option<HandVal> {
+        if !(5..=7).contains(&partial.len()) {
+            return None;
+        }
+        let cards: Vec<(u32, u32)> = partial
+            .cards()
+            .iter()
+            .map(|card| (card.rank as u32, card.suit as u32 + 1))
+            .collect();
+        Some(HandVal(bitmask::evaluate(&cards)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        texas_hold_em::evaluation::{evaluator::Evaluator, hand_rank::HandRank},
+        Card, Rank, Suit,
+    };
+
+    use super::BitmaskEvaluator;
+
+    #[rstest]
+    #[case([
+        Card::new(Suit::Spade, Rank::Ace),
+        Card::new(Suit::Spade, Rank::Queen),
+        Card::new(Suit::Club, Rank::Two),
+        Card::new(Suit::Heart, Rank::King),
+        Card::new(Suit::Spade, Rank::King),
+        Card::new(Suit::Spade, Rank::Ten),
+        Card::new(Suit::Spade, Rank::Jack),
+    ], HandRank::StraightFlush)]
+    #[case([
+        Card::new(Suit::Club, Rank::Nine),
+        Card::new(Suit::Diamond, Rank::Nine),
+        Card::new(Suit::Heart, Rank::Nine),
+        Card::new(Suit::Spade, Rank::Nine),
+        Card::new(Suit::Club, Rank::Ten),
+        Card::new(Suit::Diamond, Rank::Jack),
+        Card::new(Suit::Heart, Rank::King),
+    ], HandRank::FourOfAKind)]
+    #[case([
+        Card::new(Suit::Club, Rank::Nine),
+        Card::new(Suit::Heart, Rank::Nine),
+        Card::new(Suit::Club, Rank::Ten),
+        Card::new(Suit::Heart, Rank::King),
+        Card::new(Suit::Club, Rank::Two),
+        Card::new(Suit::Club, Rank::Five),
+        Card::new(Suit::Club, Rank::Four),
+    ], HandRank::Flush)]
+    #[case([
+        Card::new(Suit::Spade, Rank::Ace),
+        Card::new(Suit::Club, Rank::Two),
+        Card::new(Suit::Club, Rank::Three),
+        Card::new(Suit::Heart, Rank::Four),
+        Card::new(Suit::Spade, Rank::Five),
+        Card::new(Suit::Diamond, Rank::Ten),
+        Card::new(Suit::Spade, Rank::Jack),
+    ], HandRank::Straight)]
+    #[case([
+        Card::new(Suit::Spade, Rank::Nine),
+        Card::new(Suit::Club, Rank::Ace),
+        Card::new(Suit::Club, Rank::Queen),
+        Card::new(Suit::Heart, Rank::King),
+        Card::new(Suit::Spade, Rank::Two),
+        Card::new(Suit::Diamond, Rank::Jack),
+        Card::new(Suit::Spade, Rank::Three),
+    ], HandRank::HighCard)]
+    fn scores_hand_rank(#[case] cards: [Card; 7], #[case] expected: HandRank) {
+        let evaluator = BitmaskEvaluator;
+        assert_eq!(
+            expected,
+            HandRank::try_from(evaluator.evaluate_hand(&cards)).unwrap()
+        )
+    }
+
+    #[test]
+    fn values_can_be_compared_across_hand_sizes() {
+        let evaluator = BitmaskEvaluator;
+        let royal_flush = [
+            Card::new(Suit::Spade, Rank::Ace),
+            Card::new(Suit::Spade, Rank::King),
+            Card::new(Suit::Spade, Rank::Queen),
+            Card::new(Suit::Spade, Rank::Jack),
+            Card::new(Suit::Spade, Rank::Ten),
+        ];
+        let mut seven_cards = royal_flush.to_vec();
+        seven_cards.push(Card::new(Suit::Club, Rank::Two));
+        seven_cards.push(Card::new(Suit::Diamond, Rank::Two));
+        let four_aces = [
+            Card::new(Suit::Club, Rank::Ace),
+            Card::new(Suit::Diamond, Rank::Ace),
+            Card::new(Suit::Heart, Rank::Ace),
+            Card::new(Suit::Spade, Rank::Ace),
+            Card::new(Suit::Club, Rank::King),
+            Card::new(Suit::Club, Rank::Two),
+        ];
+
+        let five = evaluator.evaluate_cards(&royal_flush).unwrap();
+        let six = evaluator.evaluate_cards(&four_aces).unwrap();
+        let seven = evaluator.evaluate_cards(&seven_cards).unwrap();
+        assert_eq!(five, seven);
+        assert!(five > six);
+        assert_eq!(HandRank::FourOfAKind, HandRank::try_from(six).unwrap());
+    }
+
+    #[test]
+    fn finds_best_five_cards() {
+        let evaluator = BitmaskEvaluator;
+        let cards = [
+            Card::new(Suit::Club, Rank::Nine),
+            Card::new(Suit::Heart, Rank::Nine),
+            Card::new(Suit::Club, Rank::Ten),
+            Card::new(Suit::Heart, Rank::King),
+            Card::new(Suit::Club, Rank::Two),
+            Card::new(Suit::Club, Rank::Five),
+            Card::new(Suit::Club, Rank::Four),
+        ];
+        let (best, value) = evaluator.best_five_cards(&cards).unwrap();
+        assert_eq!(
+            [
+                Card::new(Suit::Club, Rank::Nine),
+                Card::new(Suit::Club, Rank::Ten),
+                Card::new(Suit::Club, Rank::Two),
+                Card::new(Suit::Club, Rank::Five),
+                Card::new(Suit::Club, Rank::Four),
+            ],
+            best
+        );
+        assert_eq!(evaluator.evaluate_hand(&cards), value);
+    }
+
+    #[cfg(feature = "two-plus-two")]
+    #[test]
+    fn agrees_with_two_plus_two_evaluator() {
+        use std::path::PathBuf;
+
+        use crate::{
+            texas_hold_em::evaluation::two_plus_two_evaluator::TwoPlusTwoEvaluator, Deck,
+            ShuffleRng,
+        };
+
+        let path = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
+        let two_plus_two = TwoPlusTwoEvaluator::create_from_path(&path).unwrap();
+        let mut rng = ShuffleRng::seeded(0);
+        for _ in 0..10_000 {
+            let deck = Deck::new().shuffle_with(&mut rng);
+            let cards: [Card; 7] = deck.cards[..7].try_into().unwrap();
+            assert_eq!(
+                two_plus_two.evaluate_hand(&cards),
+                BitmaskEvaluator.evaluate_hand(&cards)
+            );
+        }
+    }
+}
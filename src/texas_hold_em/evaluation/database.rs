@@ -1,29 +1,204 @@
-use anyhow::Result;
-use std::{fs, path::Path};
+use memmap2::Mmap;
+use std::{
+    fmt::Display,
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-pub struct DataBase(pub Vec<u32>);
+use super::{bitmask, hand_ranks_generator::TABLE_SIZE};
+
+/// The CRC-32 of the table written by the generator.
+const CHECKSUM: u32 = 0x7808_da57;
+
+/// Hands, as table card numbers, that are looked up to check the table has the expected layout.
+const SAMPLE_HANDS: [[u32; 7]; 4] = [
+    [1, 2, 3, 4, 5, 6, 7],
+    [52, 48, 44, 40, 36, 1, 2],
+    [1, 5, 9, 13, 18, 22, 27],
+    [50, 49, 46, 45, 3, 8, 12],
+];
+
+/// Why the HandRanks table could not be loaded.
+#[derive(Debug)]
+pub enum DataBaseError {
+    Missing(PathBuf),
+    Io(io::Error),
+    /// The file is shorter than the table, usually because it was not completely written.
+    Truncated {
+        expected: usize,
+        found: usize,
+    },
+    /// The file is not a HandRanks table, or has been corrupted.
+    WrongFormat(String),
+}
+
+impl Display for DataBaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataBaseError::Missing(path) => {
+                write!(f, "Hand ranks file {} does not exist", path.display())
+            }
+            DataBaseError::Io(err) => write!(f, "Could not read hand ranks file: {}", err),
+            DataBaseError::Truncated { expected, found } => write!(
+                f,
+                "Hand ranks file is truncated, expected {} bytes but found {}",
+                expected, found
+            ),
+            DataBaseError::WrongFormat(reason) => {
+                write!(
+                    f,
+                    "Hand ranks file is not in the expected format: {}",
+                    reason
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataBaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataBaseError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DataBaseError {
+    fn from(value: io::Error) -> Self {
+        DataBaseError::Io(value)
+    }
+}
+
+/// The HandRanks lookup table, memory mapped from disk.
+///
+/// Clones share the same mapping, so they are cheap to pass between threads.
+#[derive(Debug, Clone)]
+pub struct DataBase(Arc<Mmap>);
 
 impl DataBase {
-    pub fn load_from_path(path: &Path) -> Result<Self> {
-        let data = fs::read(path)?;
-        let data = Self::format_data(data)?;
+    /// Maps the table at `path` into memory.
+    ///
+    /// The file must not be truncated or modified while the table is loaded, since reading a
+    /// mapped file that changes underneath is undefined behaviour.
+    pub fn load_from_path(path: &Path) -> Result<Self, DataBaseError> {
+        let file = File::open(path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => DataBaseError::Missing(path.to_path_buf()),
+            _ => DataBaseError::Io(err),
+        })?;
+        // Safety: the table is only read through the mapping. Truncating or modifying the file
+        // while it is mapped, as rebuilding HandRanks.dat does, is undefined behaviour, so callers
+        // must not change the file while a DataBase loaded from it is alive.
+        let data = unsafe { Mmap::map(&file)? };
+        Self::validate(&data)?;
 
-        Ok(DataBase(data))
+        Ok(DataBase(Arc::new(data)))
     }
 
-    fn format_data(value: Vec<u8>) -> Result<Vec<u32>> {
-        let mut contents = vec![];
+    /// The entry at `index` in the table, or zero if the index is outside the table.
+    pub fn get(&self, index: u32) -> u32 {
+        entry(&self.0, index)
+    }
+
+    fn validate(data: &[u8]) -> Result<(), DataBaseError> {
+        let expected = TABLE_SIZE * 4;
+        if data.len() < expected {
+            return Err(DataBaseError::Truncated {
+                expected,
+                found: data.len(),
+            });
+        }
+        if data.len() > expected {
+            return Err(DataBaseError::WrongFormat(format!(
+                "expected {} bytes but found {}",
+                expected,
+                data.len()
+            )));
+        }
 
-        let vec_length = value.len();
-        for i in 0..vec_length {
-            let range = i * 4..(i * 4) + 4;
-            if range.end > vec_length {
-                continue;
+        for hand in SAMPLE_HANDS {
+            let value = hand
+                .iter()
+                .fold(53, |result, card| entry(data, result + card));
+            let cards = hand.map(|card| ((card - 1) / 4, (card - 1) % 4 + 1));
+            if value != bitmask::evaluate(&cards) {
+                return Err(DataBaseError::WrongFormat(format!(
+                    "hand {:?} has value {}",
+                    hand, value
+                )));
             }
-            let bytes = &value[range].try_into()?;
-            contents.push(u32::from_le_bytes(*bytes));
         }
 
-        Ok(contents)
+        let checksum = crc32fast::hash(data);
+        if checksum != CHECKSUM {
+            return Err(DataBaseError::WrongFormat(format!(
+                "checksum {:08x} does not match {:08x}",
+                checksum, CHECKSUM
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn entry(data: &[u8], index: u32) -> u32 {
+    let start = index as usize * 4;
+    data.get(start..start + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::texas_hold_em::evaluation::hand_ranks_generator::TABLE_SIZE;
+
+    use super::{DataBase, DataBaseError};
+
+    fn write_temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let result = DataBase::load_from_path(&PathBuf::from("does-not-exist/HandRanks.dat"));
+        assert!(matches!(result, Err(DataBaseError::Missing(_))));
+    }
+
+    #[test]
+    fn reports_truncated_file() {
+        let path = write_temp_file("truncated-hand-ranks.dat", &[0; 1024]);
+        let result = DataBase::load_from_path(&path);
+        fs::remove_file(path).unwrap();
+        assert!(matches!(
+            result,
+            Err(DataBaseError::Truncated { found: 1024, .. })
+        ));
+    }
+
+    #[test]
+    fn reports_file_that_is_too_long() {
+        let result = DataBase::validate(&vec![0; TABLE_SIZE * 4 + 4]);
+        assert!(matches!(result, Err(DataBaseError::WrongFormat(_))));
+    }
+
+    #[test]
+    fn reports_file_with_wrong_contents() {
+        let result = DataBase::validate(&vec![0; TABLE_SIZE * 4]);
+        assert!(matches!(result, Err(DataBaseError::WrongFormat(_))));
+    }
+
+    #[test]
+    fn lookups_outside_the_table_are_zero() {
+        let data = 7u32.to_le_bytes();
+        assert_eq!(7, super::entry(&data, 0));
+        assert_eq!(0, super::entry(&data, 1));
+        assert_eq!(0, super::entry(&data, u32::MAX));
     }
 }
@@ -1,8 +1,215 @@
-use crate::Card;
+use crate::{Card, CardSet};
 
-pub trait Evaluator {
+use super::bitmask_evaluator::BitmaskEvaluator;
+
+/// Scores hands so they can be compared. Evaluators can be shared between threads.
+pub trait Evaluator: Send + Sync {
     fn evaluate_hand(&self, cards: &[Card; 7]) -> HandVal;
+
+    /// Starts an evaluation that cards are added to one at a time.
+    ///
+    /// Cards shared by several hands, like the board, can be added once and the partial evaluation
+    /// copied for each hand.
+    fn start_evaluation(&self) -> PartialEvaluation {
+        PartialEvaluation::default()
+    }
+
+    fn add_card(&self, partial: PartialEvaluation, card: Card) -> PartialEvaluation {
+        partial.with_card(card)
+    }
+
+    fn add_cards(&self, partial: PartialEvaluation, cards: &[Card]) -> PartialEvaluation {
+        cards
+            .iter()
+            .fold(partial, |partial, card| self.add_card(partial, *card))
+    }
+
+    /// The value of the best five card hand in a partial evaluation.
+    ///
+    /// Returns `None` if the evaluation does not have a supported number of cards. The default
+    /// implementation only supports seven cards.
+    fn finish_evaluation(&self, partial: PartialEvaluation) -> Option<HandVal> {
+        let cards: [Card; 7] = partial.cards().try_into().ok()?;
+        Some(self.evaluate_hand(&cards))
+    }
+
+    /// Evaluates the best five card hand from five, six or seven cards.
+    ///
+    /// Values can be compared whatever the number of cards. Returns `None` if there are too few or
+    /// too many cards, a card is repeated, or the evaluator does not support that number of cards.
+    fn evaluate_cards(&self, cards: &[Card]) -> Option<HandVal> {
+        let partial = self.add_cards(self.start_evaluation(), cards);
+        if !(5..=7).contains(&cards.len()) || partial.len() != cards.len() {
+            return None;
+        }
+        self.finish_evaluation(partial)
+    }
+
+    /// The five cards making the best hand from five, six or seven cards, and its value.
+    ///
+    /// Returns `None` if the cards cannot be evaluated.
+    fn best_five_cards(&self, cards: &[Card]) -> Option<([Card; 5], HandVal)> {
+        self.evaluate_cards(cards)?;
+        (0u32..1 << cards.len())
+            .filter(|selection| selection.count_ones() == 5)
+            .filter_map(|selection| {
+                let five: Vec<Card> = (0..cards.len())
+                    .filter(|i| selection & (1 << i) != 0)
+                    .map(|i| cards[i])
+                    .collect();
+                let value = self.evaluate_cards(&five)?;
+                Some((five.try_into().ok()?, value))
+            })
+            .max_by_key(|(_, value)| *value)
+    }
+
+    fn evaluate_many(&self, hands: &[[Card; 7]]) -> Vec<HandVal> {
+        hands.iter().map(|hand| self.evaluate_hand(hand)).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 pub struct HandVal(pub u32);
+
+/// The cards added to an evaluation so far, and the evaluator's state after adding them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PartialEvaluation {
+    /// A bit for each card added, set at the card's index.
+    pub card_mask: u64,
+    /// State kept by the evaluator, such as a position in a lookup table.
+    pub state: u32,
+}
+
+impl PartialEvaluation {
+    pub fn with_card(mut self, card: Card) -> Self {
+        self.card_mask |= 1 << card.index();
+        self
+    }
+
+    pub fn with_state(mut self, state: u32) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.card_mask.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.card_mask == 0
+    }
+
+    /// The cards added so far, in index order.
+    pub fn cards(&self) -> Vec<Card> {
+        CardSet(self.card_mask).iter().collect()
+    }
+}
+
+/// Creates an evaluator that needs no data files.
+pub fn default_evaluator() -> Box<dyn Evaluator> {
+    Box::new(BitmaskEvaluator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Card, Rank, Suit};
+
+    use super::{Evaluator, HandVal, PartialEvaluation};
+
+    struct CountingEvaluator;
+
+    impl Evaluator for CountingEvaluator {
+        fn evaluate_hand(&self, cards: &[Card; 7]) -> HandVal {
+            HandVal(cards.iter().map(|card| card.rank as u32).sum())
+        }
+    }
+
+    fn cards() -> [Card; 7] {
+        [
+            Card::new(Suit::Club, Rank::Two),
+            Card::new(Suit::Diamond, Rank::Three),
+            Card::new(Suit::Heart, Rank::Four),
+            Card::new(Suit::Spade, Rank::Five),
+            Card::new(Suit::Club, Rank::Six),
+            Card::new(Suit::Diamond, Rank::Seven),
+            Card::new(Suit::Heart, Rank::Eight),
+        ]
+    }
+
+    #[test]
+    fn partial_evaluation_tracks_cards() {
+        let partial = PartialEvaluation::default()
+            .with_card(Card::new(Suit::Spade, Rank::Ace))
+            .with_card(Card::new(Suit::Club, Rank::Two));
+        assert_eq!(2, partial.len());
+        assert_eq!(
+            vec![
+                Card::new(Suit::Club, Rank::Two),
+                Card::new(Suit::Spade, Rank::Ace)
+            ],
+            partial.cards()
+        );
+    }
+
+    #[test]
+    fn default_partial_evaluation_matches_evaluate_hand() {
+        let evaluator = CountingEvaluator;
+        let cards = cards();
+        let board = evaluator.add_cards(evaluator.start_evaluation(), &cards[2..]);
+        let partial = evaluator.add_cards(board, &cards[..2]);
+        assert_eq!(
+            Some(evaluator.evaluate_hand(&cards)),
+            evaluator.finish_evaluation(partial)
+        );
+    }
+
+    #[test]
+    fn default_evaluation_only_supports_seven_cards() {
+        let evaluator = CountingEvaluator;
+        let partial = evaluator.add_cards(evaluator.start_evaluation(), &cards()[..5]);
+        assert_eq!(None, evaluator.finish_evaluation(partial));
+        assert_eq!(None, evaluator.evaluate_cards(&cards()[..5]));
+        assert_eq!(None, evaluator.evaluate_cards(&cards()[..6]));
+        assert_eq!(None, evaluator.best_five_cards(&cards()));
+    }
+
+    #[test]
+    fn evaluates_seven_card_slice() {
+        let evaluator = CountingEvaluator;
+        assert_eq!(Some(HandVal(21)), evaluator.evaluate_cards(&cards()));
+    }
+
+    #[test]
+    fn cannot_evaluate_wrong_number_of_cards() {
+        let evaluator = CountingEvaluator;
+        assert_eq!(None, evaluator.evaluate_cards(&cards()[..4]));
+        let mut eight_cards = cards().to_vec();
+        eight_cards.push(Card::new(Suit::Spade, Rank::Ace));
+        assert_eq!(None, evaluator.evaluate_cards(&eight_cards));
+    }
+
+    #[test]
+    fn cannot_evaluate_repeated_cards() {
+        let evaluator = CountingEvaluator;
+        let mut cards = cards();
+        cards[1] = cards[0];
+        assert_eq!(None, evaluator.evaluate_cards(&cards));
+    }
+
+    #[test]
+    fn cannot_find_best_five_cards_of_too_few_cards() {
+        let evaluator = CountingEvaluator;
+        assert_eq!(None, evaluator.best_five_cards(&cards()[..4]));
+    }
+
+    #[test]
+    fn evaluates_many_hands() {
+        let evaluator = CountingEvaluator;
+        let mut other = cards();
+        other[0] = Card::new(Suit::Club, Rank::Ace);
+        assert_eq!(
+            vec![HandVal(21), HandVal(33)],
+            evaluator.evaluate_many(&[cards(), other])
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+use strum::IntoEnumIterator;
+
+use crate::Rank;
+
+use super::{bitmask, evaluator::HandVal, hand_rank::HandRank};
+
+/// A hand value decoded into its category and the ranks of the five cards that make it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandDescription {
+    pub hand_rank: HandRank,
+    /// The ranks of the five cards, most significant first. Groups of cards come before single
+    /// cards, so a full house lists its three of a kind before its pair.
+    pub ranks: [Rank; 5],
+}
+
+impl HandDescription {
+    /// The ranks that make up the combination, such as the two pairs in two pair.
+    pub fn made_ranks(&self) -> &[Rank] {
+        &self.ranks[..self.made_len()]
+    }
+
+    /// The ranks that only break ties, after the combination.
+    pub fn kickers(&self) -> &[Rank] {
+        &self.ranks[self.made_len()..]
+    }
+
+    fn made_len(&self) -> usize {
+        match self.hand_rank {
+            HandRank::HighCard => 1,
+            HandRank::Pair => 2,
+            HandRank::TwoPair => 4,
+            HandRank::ThreeOfAKind => 3,
+            HandRank::Straight => 5,
+            HandRank::Flush => 1,
+            HandRank::FullHouse => 5,
+            HandRank::FourOfAKind => 4,
+            HandRank::StraightFlush => 5,
+        }
+    }
+}
+
+impl TryFrom<HandVal> for HandDescription {
+    type Error = String;
+
+    fn try_from(value: HandVal) -> Result<Self, Self::Error> {
+        let hand_rank = HandRank::try_from(value)?;
+        let ranks = descriptions()
+            .get(&value.0)
+            .copied()
+            .ok_or_else(|| format!("HandVal {} not recognized", value.0))?;
+        Ok(HandDescription { hand_rank, ranks })
+    }
+}
+
+impl Display for HandDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [first, _, third, ..] = self.ranks;
+        match self.hand_rank {
+            HandRank::StraightFlush if first == Rank::Ace => write!(f, "Royal flush"),
+            HandRank::StraightFlush => write!(f, "{}-high straight flush", first.name()),
+            HandRank::FourOfAKind => write!(f, "Four {}", first.plural_name()),
+            HandRank::FullHouse => write!(
+                f,
+                "{} full of {}",
+                first.plural_name(),
+                self.ranks[3].plural_name()
+            ),
+            HandRank::Flush => write!(f, "{}-high flush", first.name()),
+            HandRank::Straight => write!(f, "{}-high straight", first.name()),
+            HandRank::ThreeOfAKind => write!(f, "Three {}", first.plural_name()),
+            HandRank::TwoPair => write!(f, "{} and {}", first.plural_name(), third.plural_name()),
+            HandRank::Pair => write!(f, "Pair of {}", first.plural_name()),
+            HandRank::HighCard => write!(f, "{} high", first.name()),
+        }?;
+        match self.kickers().first() {
+            Some(kicker) => write!(f, ", {} kicker", kicker.name()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The ranks of every distinct hand, by value.
+fn descriptions() -> &'static HashMap<u32, [Rank; 5]> {
+    static DESCRIPTIONS: OnceLock<HashMap<u32, [Rank; 5]>> = OnceLock::new();
+    DESCRIPTIONS.get_or_init(|| {
+        let mut descriptions = HashMap::new();
+        add_descriptions(&mut descriptions, &mut Vec::with_capacity(5));
+        descriptions
+    })
+}
+
+/// Adds every hand made by extending `ranks`, which are in descending order, to five cards.
+fn add_descriptions(descriptions: &mut HashMap<u32, [Rank; 5]>, ranks: &mut Vec<usize>) {
+    if ranks.len() == 5 {
+        // Alternating suits never make a flush, while giving repeated ranks different suits
+        let cards: Vec<(u32, u32)> = ranks
+            .iter()
+            .enumerate()
+            .map(|(i, &rank)| (rank as u32, i as u32 % 4 + 1))
+            .collect();
+        let ordered = order_by_significance(ranks);
+        descriptions.insert(bitmask::evaluate(&cards), ordered);
+
+        if ranks.windows(2).all(|pair| pair[0] != pair[1]) {
+            let suited: Vec<(u32, u32)> = ranks.iter().map(|&rank| (rank as u32, 1)).collect();
+            descriptions.insert(bitmask::evaluate(&suited), ordered);
+        }
+        return;
+    }
+
+    let highest = ranks.last().copied().unwrap_or(12);
+    for rank in (0..=highest).rev() {
+        if ranks.iter().filter(|&&other| other == rank).count() < 4 {
+            ranks.push(rank);
+            add_descriptions(descriptions, ranks);
+            ranks.pop();
+        }
+    }
+}
+
+fn order_by_significance(ranks: &[usize]) -> [Rank; 5] {
+    let count = |rank: usize| ranks.iter().filter(|&&other| other == rank).count();
+    let mut ordered: Vec<usize> = ranks.to_vec();
+    ordered.sort_by(|a, b| count(*b).cmp(&count(*a)).then(b.cmp(a)));
+
+    // In a five-high straight the ace plays low
+    if ordered == [12, 3, 2, 1, 0] {
+        ordered.rotate_left(1);
+    }
+    let ordered: Vec<Rank> = ordered
+        .into_iter()
+        .map(|rank| Rank::iter().nth(rank).expect("Rank should be below 13"))
+        .collect();
+    ordered.try_into().expect("Hand should have five ranks")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        poker::card::parse_cards,
+        texas_hold_em::evaluation::{
+            bitmask_evaluator::BitmaskEvaluator, evaluator::Evaluator, hand_rank::HandRank,
+        },
+        Rank,
+    };
+
+    use super::HandDescription;
+
+    fn describe(cards: &str) -> HandDescription {
+        let cards = parse_cards(cards).unwrap();
+        let value = BitmaskEvaluator.evaluate_cards(&cards).unwrap();
+        HandDescription::try_from(value).unwrap()
+    }
+
+    #[rstest]
+    #[case("As Ks Qs Js Ts", "Royal flush")]
+    #[case("As 2s 3s 4s 5s", "Five-high straight flush")]
+    #[case("9s 9c 9h 9d 2s", "Four Nines, Two kicker")]
+    #[case("Ks Kc Kh Td Ts", "Kings full of Tens")]
+    #[case("Ah Kh 2h 7h 9h", "Ace-high flush, King kicker")]
+    #[case("Th 9c 8h 7h 6h", "Ten-high straight")]
+    #[case("3h 3c 3s Ah 6h", "Three Threes, Ace kicker")]
+    #[case("Jh Jc 4s 4h 6h", "Jacks and Fours, Six kicker")]
+    #[case("6h 6c 4s Kh 2h", "Pair of Sixes, King kicker")]
+    #[case("Qh 6c 4s Jh 2h", "Queen high, Jack kicker")]
+    fn can_be_displayed(#[case] cards: &str, #[case] expected: &str) {
+        assert_eq!(expected, describe(cards).to_string());
+    }
+
+    #[test]
+    fn lists_made_ranks_before_kickers() {
+        let description = describe("2h Jc 4s Jh 4h");
+        assert_eq!(HandRank::TwoPair, description.hand_rank);
+        assert_eq!(
+            [Rank::Jack, Rank::Jack, Rank::Four, Rank::Four],
+            description.made_ranks()
+        );
+        assert_eq!([Rank::Two], description.kickers());
+    }
+
+    #[test]
+    fn describes_every_hand_value() {
+        assert_eq!(7462, super::descriptions().len());
+    }
+
+    #[test]
+    fn cannot_describe_invalid_value() {
+        assert!(
+            HandDescription::try_from(crate::texas_hold_em::evaluation::evaluator::HandVal(0))
+                .is_err()
+        );
+    }
+}
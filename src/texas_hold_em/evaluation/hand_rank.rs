@@ -1,4 +1,8 @@
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+use std::fmt::Display;
+
+use super::evaluator::HandVal;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum HandRank {
     HighCard,
     Pair,
@@ -10,3 +14,40 @@ pub enum HandRank {
     FourOfAKind,
     StraightFlush,
 }
+
+impl TryFrom<HandVal> for HandRank {
+    type Error = String;
+
+    fn try_from(value: HandVal) -> Result<Self, Self::Error> {
+        let result = value.0 >> 12;
+        match result {
+            1 => Ok(HandRank::HighCard),
+            2 => Ok(HandRank::Pair),
+            3 => Ok(HandRank::TwoPair),
+            4 => Ok(HandRank::ThreeOfAKind),
+            5 => Ok(HandRank::Straight),
+            6 => Ok(HandRank::Flush),
+            7 => Ok(HandRank::FullHouse),
+            8 => Ok(HandRank::FourOfAKind),
+            9 => Ok(HandRank::StraightFlush),
+            _ => Err(format!("HandVal {} not recognized", result)),
+        }
+    }
+}
+
+impl Display for HandRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HandRank::HighCard => "High card",
+            HandRank::Pair => "Pair",
+            HandRank::TwoPair => "Two pair",
+            HandRank::ThreeOfAKind => "Three of a kind",
+            HandRank::Straight => "Straight",
+            HandRank::Flush => "Flush",
+            HandRank::FullHouse => "Full house",
+            HandRank::FourOfAKind => "Four of a kind",
+            HandRank::StraightFlush => "Straight flush",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,222 @@
+//! Generates the `HandRanks.dat` lookup table used by the two plus two evaluator.
+//!
+//! Every sequence of up to seven cards is a path through a directed graph, stored as one flat
+//! array. Each node holds 53 entries: entry zero is the value of the hand so far (for five and six
+//! cards) and entries 1-52 point at the node reached by adding that card. After the seventh card the
+//! entry holds the value of the hand instead of a pointer.
+//!
+//! Nodes are identified by the sorted cards they contain, ignoring suits that can no longer make a
+//! flush, so equivalent hands share a node. This is a port of Ray Wotton's generator from the two
+//! plus two forums and produces an identical table.
+//!
+//! This module only depends on [`super::bitmask`], so it can be used by the build script.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use super::bitmask::evaluate;
+
+/// The number of entries in the table.
+pub const TABLE_SIZE: usize = 32_487_834;
+
+/// The number of distinct hands of up to six cards, plus an empty hand at index zero.
+const MAX_IDS: usize = 612_978;
+
+/// Cards are numbered 1-52 in the table, in rank then suit order, so 2c = 1, 2d = 2 ... As = 52.
+const CARDS: u32 = 52;
+
+/// Writes the table to `path` as little-endian 32-bit integers.
+pub fn write_to(path: &Path) -> io::Result<()> {
+    let table = generate();
+    let mut writer = BufWriter::new(File::create(path)?);
+    for entry in table {
+        writer.write_all(&entry.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+/// Generates the table.
+pub fn generate() -> Vec<u32> {
+    let mut ids = HandIds::default();
+
+    // Find every hand of up to six cards. The list grows as it is walked.
+    let mut id_num = 0;
+    while id_num == 0 || ids.ids[id_num] != 0 {
+        for card in 1..=CARDS {
+            let (id, num_cards) = make_id(ids.ids[id_num], card);
+            if num_cards < 7 {
+                ids.save(id);
+            }
+        }
+        id_num += 1;
+    }
+
+    // Link each hand to the hands made by adding another card, which no longer changes the list.
+    let mut table = vec![0; TABLE_SIZE];
+    let mut id_num = 0;
+    while id_num == 0 || ids.ids[id_num] != 0 {
+        let mut num_cards = 0;
+        for card in 1..=CARDS {
+            let (id, cards) = make_id(ids.ids[id_num], card);
+            num_cards = cards;
+            let entry = if num_cards < 7 {
+                ids.save(id) as u32 * 53 + 53
+            } else {
+                evaluate_id(id)
+            };
+            table[id_num * 53 + card as usize + 53] = entry;
+        }
+
+        if num_cards == 6 || num_cards == 7 {
+            table[id_num * 53 + 53] = evaluate_id(ids.ids[id_num]);
+        }
+        id_num += 1;
+    }
+
+    table
+}
+
+/// The hand ids found so far, kept in ascending order.
+struct HandIds {
+    ids: Vec<i64>,
+    len: usize,
+}
+
+impl Default for HandIds {
+    fn default() -> Self {
+        HandIds {
+            ids: vec![0; MAX_IDS],
+            len: 1,
+        }
+    }
+}
+
+impl HandIds {
+    /// Inserts the id if it is new, and returns its index.
+    fn save(&mut self, id: i64) -> usize {
+        if id == 0 {
+            return 0;
+        }
+
+        let max_id = self.ids[self.len - 1];
+        if id >= max_id {
+            if id > max_id {
+                self.ids[self.len] = id;
+                self.len += 1;
+            }
+            return self.len - 1;
+        }
+
+        let mut low = 0;
+        let mut high = self.len - 1;
+        while high - low > 1 {
+            let middle = (high + low).div_ceil(2);
+            match self.ids[middle].cmp(&id) {
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Less => low = middle,
+                std::cmp::Ordering::Equal => return middle,
+            }
+        }
+
+        self.ids.copy_within(high..self.len, high + 1);
+        self.ids[high] = id;
+        self.len += 1;
+        high
+    }
+}
+
+/// Adds a card to a hand id, returning the new id and the number of cards in it.
+///
+/// Ids store one card per byte as `rrrrssss`, with rank 1-13 and suit 1-4, sorted in descending
+/// order. Suits that can no longer make a flush are set to zero. The id is zero if the card is
+/// already in the hand.
+fn make_id(id: i64, new_card: u32) -> (i64, usize) {
+    let mut work = [0u32; 8];
+    let mut suit_count = [0usize; 5];
+    let mut rank_count = [0usize; 14];
+
+    for (card_num, card) in work[1..7].iter_mut().enumerate() {
+        *card = ((id >> (8 * card_num)) & 0xff) as u32;
+    }
+
+    let new_card = new_card - 1;
+    work[0] = (((new_card >> 2) + 1) << 4) + (new_card & 3) + 1;
+
+    let mut num_cards = 0;
+    let mut duplicate = false;
+    while work[num_cards] != 0 {
+        suit_count[(work[num_cards] & 0xf) as usize] += 1;
+        rank_count[((work[num_cards] >> 4) & 0xf) as usize] += 1;
+        if num_cards > 0 && work[0] == work[num_cards] {
+            duplicate = true;
+        }
+        num_cards += 1;
+    }
+    if duplicate {
+        return (0, num_cards);
+    }
+
+    if num_cards > 4 && rank_count[1..14].iter().any(|&count| count > 4) {
+        return (0, num_cards);
+    }
+
+    // Suits only matter while there are enough cards of that suit left to make a flush.
+    let need_suited = num_cards as isize - 2;
+    if need_suited > 1 {
+        for card in work[..num_cards].iter_mut() {
+            if (suit_count[(*card & 0xf) as usize] as isize) < need_suited {
+                *card &= 0xf0;
+            }
+        }
+    }
+
+    work[..7].sort_unstable_by(|a, b| b.cmp(a));
+
+    let id = work[..7]
+        .iter()
+        .enumerate()
+        .fold(0, |id, (i, &card)| id + ((card as i64) << (8 * i)));
+    (id, num_cards)
+}
+
+/// Evaluates the five, six or seven cards in a hand id.
+fn evaluate_id(id: i64) -> u32 {
+    if id == 0 {
+        return 0;
+    }
+    let cards: Vec<(u32, u32)> = (0..7)
+        .map(|i| ((id >> (8 * i)) & 0xff) as u32)
+        .take_while(|&card| card != 0)
+        .map(|card| ((card >> 4) - 1, card & 0xf))
+        .collect();
+    evaluate(&cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::make_id;
+
+    #[test]
+    fn duplicate_card_makes_empty_id() {
+        let (id, _) = make_id(0, 1);
+        assert_eq!(0, make_id(id, 1).0);
+    }
+
+    #[test]
+    fn ids_ignore_suits_that_cannot_make_a_flush() {
+        let (two_clubs, _) = make_id(0, 1);
+        let (two_diamonds, _) = make_id(0, 2);
+        let (first, _) = make_id(make_id(two_clubs, 5).0, 9);
+        let (second, _) = make_id(make_id(two_diamonds, 5).0, 9);
+        assert_ne!(first, second);
+
+        let hand = [1, 6, 11, 16];
+        let id = hand.iter().fold(0, |id, &card| make_id(id, card).0);
+        let (with_club, _) = make_id(id, 21);
+        let (with_diamond, _) = make_id(id, 22);
+        assert_eq!(with_club, with_diamond);
+    }
+}
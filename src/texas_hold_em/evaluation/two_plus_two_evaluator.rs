@@ -1,102 +1,85 @@
 use std::path::Path;
 
-use crate::{Card, Deck};
+use crate::Card;
 
 use super::{
-    database::DataBase,
-    evaluator::{Evaluator, HandVal},
-    hand_rank::HandRank,
+    database::{DataBase, DataBaseError},
+    evaluator::{Evaluator, HandVal, PartialEvaluation},
 };
 
-use anyhow::Result;
-
-const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
-
-const SUIT_BITS: [u32; 4] = [8, 4, 2, 1];
-
 #[derive(Debug, PartialEq)]
 struct CardNum(u32);
-#[derive(Debug, PartialEq)]
-struct CardCode(u32);
 
+/// Evaluates hands with the HandRanks lookup table.
+///
+/// The table is shared between clones, so cloning an evaluator is cheap.
+#[derive(Debug, Clone)]
 pub struct TwoPlusTwoEvaluator {
     data: DataBase,
-    deck: Vec<CardCode>,
 }
 
 impl Evaluator for TwoPlusTwoEvaluator {
     fn evaluate_hand(&self, cards: &[Card; 7]) -> HandVal {
-        let mut result = 53;
-        let card_nums = cards
-            .iter()
-            .map(|card| TwoPlusTwoEvaluator::card_to_num(self, card));
-        for num in card_nums {
-            result = self.data.0[(result + num.0) as usize];
-        }
+        let result = cards.iter().fold(53, |result, card| {
+            self.data
+                .get(result + TwoPlusTwoEvaluator::card_to_num(card).0)
+        });
         HandVal(result)
     }
-}
-
-impl TwoPlusTwoEvaluator {
-    pub fn create_from_path(path: &Path) -> Result<Self> {
-        let data = DataBase::load_from_path(path)?;
 
-        let deck = Deck::new().cards.iter().map(CardCode::from).collect();
+    fn start_evaluation(&self) -> PartialEvaluation {
+        PartialEvaluation::default().with_state(53)
+    }
 
-        Ok(TwoPlusTwoEvaluator { data, deck })
+    fn add_card(&self, partial: PartialEvaluation, card: Card) -> PartialEvaluation {
+        let state = self
+            .data
+            .get(partial.state + TwoPlusTwoEvaluator::card_to_num(&card).0);
+        partial.with_card(card).with_state(state)
     }
 
-    fn card_to_num(&self, card: &Card) -> CardNum {
-        let card_code = CardCode::from(card);
-        let result = self.deck.iter().position(|code| code == &card_code);
-        CardNum(result.expect("Could not find card code in deck") as u32 + 1) // Array starts at one for some reason..
+    fn finish_evaluation(&self, partial: PartialEvaluation) -> Option<HandVal> {
+        match partial.len() {
+            // Before the seventh card, the first entry of each node holds the value so far
+            5 | 6 => Some(HandVal(self.data.get(partial.state))),
+            7 => Some(HandVal(partial.state)),
+            _ => None,
+        }
     }
 }
 
-impl From<&Card> for CardCode {
-    fn from(value: &Card) -> Self {
-        let ri = value.rank as u32;
-        CardCode(
-            PRIMES[value.rank as usize]
-                | (ri << 8)
-                | SUIT_BITS[value.suit as usize] << 12
-                | (1 << (16 + ri)),
-        )
+impl TwoPlusTwoEvaluator {
+    pub fn create_from_path(path: &Path) -> Result<Self, DataBaseError> {
+        let data = DataBase::load_from_path(path)?;
+
+        Ok(TwoPlusTwoEvaluator { data })
     }
-}
 
-impl TryFrom<HandVal> for HandRank {
-    type Error = String;
-
-    fn try_from(value: HandVal) -> Result<Self, Self::Error> {
-        let result = value.0 >> 12;
-        match result {
-            1 => Ok(HandRank::HighCard),
-            2 => Ok(HandRank::Pair),
-            3 => Ok(HandRank::TwoPair),
-            4 => Ok(HandRank::ThreeOfAKind),
-            5 => Ok(HandRank::Straight),
-            6 => Ok(HandRank::Flush),
-            7 => Ok(HandRank::FullHouse),
-            8 => Ok(HandRank::FourOfAKind),
-            9 => Ok(HandRank::StraightFlush),
-            _ => Err(format!("HandVal {} not recognized", result)),
-        }
+    fn card_to_num(card: &Card) -> CardNum {
+        CardNum(card.index() as u32 + 1) // The table numbers cards from one
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{path::PathBuf, sync::OnceLock};
 
-    use crate::{Rank, Suit};
+    use crate::{
+        texas_hold_em::evaluation::{bitmask_evaluator::BitmaskEvaluator, hand_rank::HandRank},
+        Deck, Rank, ShuffleRng, Suit,
+    };
 
     use super::*;
     use rstest::rstest;
 
     fn create_evaluator() -> TwoPlusTwoEvaluator {
-        let path = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
-        TwoPlusTwoEvaluator::create_from_path(&path).expect("Could not parse hand ranks db")
+        static EVALUATOR: OnceLock<TwoPlusTwoEvaluator> = OnceLock::new();
+        EVALUATOR
+            .get_or_init(|| {
+                let path = PathBuf::from("two-plus-two-hand-evaluator/HandRanks.dat");
+                TwoPlusTwoEvaluator::create_from_path(&path).expect("Could not parse hand ranks db")
+            })
+            .clone()
     }
 
     #[rstest]
@@ -105,16 +88,10 @@ mod tests {
     #[case(Card::new(Suit::Heart, Rank::Two), CardNum(3))]
     #[case(Card::new(Suit::Spade, Rank::Two), CardNum(4))]
     fn card_has_correct_card_number(#[case] card: Card, #[case] expected_card_number: CardNum) {
-        let evaluator = create_evaluator();
-        assert_eq!(expected_card_number, evaluator.card_to_num(&card));
-    }
-
-    #[rstest]
-    #[case(Card::new(Suit::Diamond, Rank::King), CardCode(134236965))]
-    #[case(Card::new(Suit::Spade, Rank::Five), CardCode(529159))]
-    #[case(Card::new(Suit::Club, Rank::Jack), CardCode(33589533))]
-    fn card_has_correct_code(#[case] card: Card, #[case] expected_card_code: CardCode) {
-        assert_eq!(expected_card_code, CardCode::from(&card));
+        assert_eq!(
+            expected_card_number,
+            TwoPlusTwoEvaluator::card_to_num(&card)
+        );
     }
 
     #[rstest]
@@ -139,11 +116,11 @@ mod tests {
     fn eval_card_num_hand(#[case] cards: [Card; 7], #[case] expected_values: [u32; 7]) {
         let evaluator = create_evaluator();
 
-        let cnums = cards.iter().map(|card| evaluator.card_to_num(card));
+        let cnums = cards.iter().map(TwoPlusTwoEvaluator::card_to_num);
 
         let mut result = 53;
         for (i, cnum) in cnums.enumerate() {
-            result = evaluator.data.0[(result + cnum.0) as usize];
+            result = evaluator.data.get(result + cnum.0);
             assert_eq!(result, expected_values[i]);
         }
     }
@@ -338,4 +315,44 @@ mod tests {
             HandRank::try_from(evaluator.evaluate_hand(&cards)).unwrap()
         )
     }
+
+    #[test]
+    fn partial_evaluation_matches_evaluate_hand() {
+        let evaluator = create_evaluator();
+        let mut rng = ShuffleRng::seeded(0);
+        for _ in 0..1000 {
+            let deck = Deck::new().shuffle_with(&mut rng);
+            let cards: [Card; 7] = deck.cards[..7].try_into().unwrap();
+            let board = evaluator.add_cards(evaluator.start_evaluation(), &cards[2..]);
+            let partial = evaluator.add_cards(board, &cards[..2]);
+            assert_eq!(
+                Some(evaluator.evaluate_hand(&cards)),
+                evaluator.finish_evaluation(partial)
+            );
+        }
+    }
+
+    #[test]
+    fn unfinished_evaluation_has_no_value() {
+        let evaluator = create_evaluator();
+        let cards = Deck::new().cards;
+        let partial = evaluator.add_cards(evaluator.start_evaluation(), &cards[..4]);
+        assert_eq!(None, evaluator.finish_evaluation(partial));
+    }
+
+    #[rstest]
+    #[case(5)]
+    #[case(6)]
+    fn evaluates_fewer_cards_like_bitmask_evaluator(#[case] count: usize) {
+        let evaluator = create_evaluator();
+        let mut rng = ShuffleRng::seeded(0);
+        for _ in 0..1000 {
+            let deck = Deck::new().shuffle_with(&mut rng);
+            let cards = &deck.cards[..count];
+            assert_eq!(
+                BitmaskEvaluator.evaluate_cards(cards),
+                evaluator.evaluate_cards(cards)
+            );
+        }
+    }
 }
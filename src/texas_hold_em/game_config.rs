@@ -0,0 +1,332 @@
+use std::fmt::Display;
+
+use crate::{player::PlayerId, BlindLevel, BlindStructure, RngSource};
+
+/// The most players a table can seat while leaving enough cards for the board.
+pub const MAX_TABLE_SEATS: u8 = 23;
+
+/// Limits on how much a player can bet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BettingStructure {
+    #[default]
+    NoLimit,
+    /// Bets and raises are limited to the size of the pot.
+    PotLimit,
+    /// Bets and raises are a fixed size, the big blind before the turn and double it afterwards.
+    FixedLimit,
+}
+
+impl Display for BettingStructure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BettingStructure::NoLimit => "No-Limit",
+            BettingStructure::PotLimit => "Pot-Limit",
+            BettingStructure::FixedLimit => "Fixed-Limit",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The parameters of a game, created with [`GameConfig::builder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameConfig {
+    /// The chips each seat starts with, which also determines the number of players.
+    pub starting_stacks: Vec<u32>,
+    pub blind_structure: BlindStructure,
+    pub betting_structure: BettingStructure,
+    pub max_seats: u8,
+    pub player_names: Vec<String>,
+    pub rng_source: RngSource,
+}
+
+impl GameConfig {
+    pub fn builder() -> GameConfigBuilder {
+        GameConfigBuilder::default()
+    }
+
+    pub fn player_count(&self) -> u8 {
+        self.starting_stacks.len() as u8
+    }
+
+    /// Player ids are assigned in seat order, starting from one.
+    pub fn player_ids(&self) -> Vec<PlayerId> {
+        (1..=self.player_count()).map(PlayerId).collect()
+    }
+
+    pub fn starting_stack(&self, player: PlayerId) -> u32 {
+        self.starting_stacks[player.0 as usize - 1]
+    }
+
+    pub fn player_name(&self, player: PlayerId) -> &str {
+        &self.player_names[player.0 as usize - 1]
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig::builder()
+            .build()
+            .expect("Default game config should be valid")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GameConfigBuilder {
+    players: u8,
+    starting_stack: u32,
+    starting_stacks: Option<Vec<u32>>,
+    blinds: Option<BlindLevel>,
+    ante: Option<u32>,
+    big_blind_ante: bool,
+    blind_structure: Option<BlindStructure>,
+    betting_structure: BettingStructure,
+    max_seats: u8,
+    player_names: Option<Vec<String>>,
+    rng_source: RngSource,
+}
+
+impl Default for GameConfigBuilder {
+    fn default() -> Self {
+        GameConfigBuilder {
+            players: 2,
+            starting_stack: 100,
+            starting_stacks: None,
+            blinds: None,
+            ante: None,
+            big_blind_ante: false,
+            blind_structure: None,
+            betting_structure: BettingStructure::default(),
+            max_seats: 9,
+            player_names: None,
+            rng_source: RngSource::default(),
+        }
+    }
+}
+
+impl GameConfigBuilder {
+    pub fn players(mut self, players: u8) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Gives every player the same starting stack.
+    pub fn starting_stack(mut self, chips: u32) -> Self {
+        self.starting_stack = chips;
+        self
+    }
+
+    /// Gives each seat its own starting stack. The number of stacks must match the number of players.
+    pub fn starting_stacks(mut self, stacks: Vec<u32>) -> Self {
+        self.starting_stacks = Some(stacks);
+        self
+    }
+
+    /// Uses fixed blinds for the whole game.
+    pub fn blinds(mut self, small_blind: u32, big_blind: u32) -> Self {
+        self.blinds = Some(BlindLevel::new(small_blind, big_blind));
+        self
+    }
+
+    /// Adds an ante paid by every player to the fixed blinds.
+    pub fn ante(mut self, ante: u32) -> Self {
+        self.ante = Some(ante);
+        self.big_blind_ante = false;
+        self
+    }
+
+    /// Adds an ante paid by the big blind on behalf of the table to the fixed blinds.
+    pub fn big_blind_ante(mut self, ante: u32) -> Self {
+        self.ante = Some(ante);
+        self.big_blind_ante = true;
+        self
+    }
+
+    /// Uses a schedule of blind levels, instead of fixed blinds.
+    pub fn blind_structure(mut self, blind_structure: BlindStructure) -> Self {
+        self.blind_structure = Some(blind_structure);
+        self
+    }
+
+    pub fn betting_structure(mut self, betting_structure: BettingStructure) -> Self {
+        self.betting_structure = betting_structure;
+        self
+    }
+
+    pub fn max_seats(mut self, max_seats: u8) -> Self {
+        self.max_seats = max_seats;
+        self
+    }
+
+    pub fn player_names(mut self, names: Vec<String>) -> Self {
+        self.player_names = Some(names);
+        self
+    }
+
+    /// Shuffles with a generator seeded from `seed`, so every hand of the session can be replayed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng_source = RngSource::Seeded(seed);
+        self
+    }
+
+    /// Shuffles with the operating system's cryptographically secure generator.
+    pub fn secure_rng(mut self) -> Self {
+        self.rng_source = RngSource::Secure;
+        self
+    }
+
+    pub fn build(self) -> Result<GameConfig, String> {
+        let starting_stacks = self
+            .starting_stacks
+            .clone()
+            .unwrap_or_else(|| vec![self.starting_stack; self.players as usize]);
+        let players = starting_stacks.len();
+
+        if self.max_seats > MAX_TABLE_SEATS {
+            return Err(format!(
+                "Table cannot have more than {} seats",
+                MAX_TABLE_SEATS
+            ));
+        }
+        if players < 2 {
+            return Err("Game requires at least two players".to_string());
+        }
+        if players > self.max_seats as usize {
+            return Err(format!(
+                "Game has {} players but only {} seats",
+                players, self.max_seats
+            ));
+        }
+        if starting_stacks.contains(&0) {
+            return Err("Starting stacks must be greater than zero".to_string());
+        }
+
+        let blind_structure = self.create_blind_structure()?;
+
+        let player_names = self
+            .player_names
+            .unwrap_or_else(|| (1..=players).map(|id| format!("Player {}", id)).collect());
+        if player_names.len() != players {
+            return Err(format!(
+                "Game has {} players but {} names",
+                players,
+                player_names.len()
+            ));
+        }
+
+        Ok(GameConfig {
+            starting_stacks,
+            blind_structure,
+            betting_structure: self.betting_structure,
+            max_seats: self.max_seats,
+            player_names,
+            rng_source: self.rng_source,
+        })
+    }
+
+    fn create_blind_structure(&self) -> Result<BlindStructure, String> {
+        let fixed_blinds_set = self.blinds.is_some() || self.ante.is_some();
+        match &self.blind_structure {
+            Some(_) if fixed_blinds_set => {
+                Err("Cannot use both fixed blinds and a blind structure".to_string())
+            }
+            Some(blind_structure) => Ok(blind_structure.clone()),
+            None => {
+                let blinds = self.blinds.unwrap_or(BlindLevel::new(5, 10));
+                let blinds = match (self.ante, self.big_blind_ante) {
+                    (None, _) => blinds,
+                    (Some(ante), false) => blinds.with_ante(ante),
+                    (Some(ante), true) => blinds.with_big_blind_ante(ante),
+                };
+                BlindStructure::fixed(blinds)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{player::PlayerId, BlindLevel, BlindStructure, RngSource};
+
+    use super::{BettingStructure, GameConfig};
+
+    #[test]
+    fn has_sensible_defaults() {
+        let config = GameConfig::default();
+        assert_eq!(vec![100, 100], config.starting_stacks);
+        assert_eq!(
+            BlindLevel::new(5, 10),
+            config.blind_structure.level_for_hand(0)
+        );
+        assert_eq!(BettingStructure::NoLimit, config.betting_structure);
+        assert_eq!("Player 2", config.player_name(PlayerId(2)));
+    }
+
+    #[test]
+    fn can_set_stacks_per_seat() {
+        let config = GameConfig::builder()
+            .starting_stacks(vec![100, 200, 300])
+            .build()
+            .unwrap();
+        assert_eq!(3, config.player_count());
+        assert_eq!(200, config.starting_stack(PlayerId(2)));
+    }
+
+    #[test]
+    fn can_set_blinds_and_ante() {
+        let config = GameConfig::builder()
+            .blinds(10, 20)
+            .ante(2)
+            .build()
+            .unwrap();
+        assert_eq!(
+            BlindLevel::new(10, 20).with_ante(2),
+            config.blind_structure.level_for_hand(0)
+        );
+    }
+
+    #[test]
+    fn can_set_seed() {
+        let config = GameConfig::builder().seed(42).build().unwrap();
+        assert_eq!(RngSource::Seeded(42), config.rng_source);
+    }
+
+    #[test]
+    fn rejects_too_few_players() {
+        assert!(GameConfig::builder().players(1).build().is_err());
+    }
+
+    #[test]
+    fn rejects_more_players_than_seats() {
+        assert!(GameConfig::builder()
+            .players(7)
+            .max_seats(6)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_empty_stacks() {
+        assert!(GameConfig::builder()
+            .starting_stacks(vec![100, 0])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_number_of_names() {
+        assert!(GameConfig::builder()
+            .players(3)
+            .player_names(vec!["Alice".to_string(), "Bob".to_string()])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_fixed_blinds_with_blind_structure() {
+        assert!(GameConfig::builder()
+            .blinds(1, 2)
+            .blind_structure(BlindStructure::default())
+            .build()
+            .is_err());
+    }
+}
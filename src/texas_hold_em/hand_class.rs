@@ -0,0 +1,187 @@
+use std::{fmt::Display, str::FromStr};
+
+use strum::IntoEnumIterator;
+
+use crate::{Card, Hand, Rank, Suit};
+
+/// A starting hand with the suits left out, such as "AKs", "QQ" or "T9o".
+///
+/// Hands in the same class are equally strong before the flop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandClass {
+    pub high: Rank,
+    pub low: Rank,
+    /// Whether both cards have the same suit. Always false for pairs.
+    pub suited: bool,
+}
+
+impl HandClass {
+    /// The number of classes: 13 pairs, and 78 each of suited and offsuit hands.
+    pub const COUNT: usize = 169;
+
+    pub fn pair(rank: Rank) -> Self {
+        HandClass {
+            high: rank,
+            low: rank,
+            suited: false,
+        }
+    }
+
+    /// The class of two different ranks, in either order.
+    pub fn new(first: Rank, second: Rank, suited: bool) -> Self {
+        HandClass {
+            high: first.max(second),
+            low: first.min(second),
+            suited: suited && first != second,
+        }
+    }
+
+    pub fn of(hand: &Hand) -> Self {
+        let [first, second] = hand.cards;
+        HandClass::new(first.rank, second.rank, first.suit == second.suit)
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// A number from 0 to 168 identifying the class.
+    ///
+    /// Classes are laid out on a 13 by 13 grid of ranks, with pairs on the diagonal, suited hands
+    /// below it and offsuit hands above it.
+    pub fn index(&self) -> usize {
+        let (high, low) = (self.high as usize, self.low as usize);
+        match self.suited {
+            true => high * 13 + low,
+            false => low * 13 + high,
+        }
+    }
+
+    /// The class with the given [`HandClass::index`], or `None` if the index is 169 or more.
+    pub fn from_index(index: usize) -> Option<Self> {
+        let row = Rank::iter().nth(index / 13)?;
+        let column = Rank::iter().nth(index % 13)?;
+        Some(HandClass::new(row, column, row > column))
+    }
+
+    /// Every class, in index order.
+    pub fn all() -> impl Iterator<Item = HandClass> {
+        (0..HandClass::COUNT).filter_map(HandClass::from_index)
+    }
+
+    /// Every hand in the class, with the higher card first.
+    pub fn combos(&self) -> Vec<Hand> {
+        let mut combos = vec![];
+        for (i, high_suit) in Suit::iter().enumerate() {
+            for (j, low_suit) in Suit::iter().enumerate() {
+                let keep = match (self.is_pair(), self.suited) {
+                    (true, _) => i < j,
+                    (false, true) => i == j,
+                    (false, false) => i != j,
+                };
+                if keep {
+                    combos.push(Hand::new([
+                        Card::new(high_suit, self.high),
+                        Card::new(low_suit, self.low),
+                    ]));
+                }
+            }
+        }
+        combos
+    }
+}
+
+impl Display for HandClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.high.symbol(), self.low.symbol())?;
+        match (self.is_pair(), self.suited) {
+            (true, _) => Ok(()),
+            (false, true) => write!(f, "s"),
+            (false, false) => write!(f, "o"),
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = String;
+
+    /// Parses a class like "QQ", "AKs" or "KTo".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("\"{}\" is not a hand class", s);
+        let mut chars = s.chars();
+        let mut rank = || {
+            chars
+                .next()
+                .and_then(|symbol| symbol.to_string().parse::<Rank>().ok())
+                .ok_or_else(invalid)
+        };
+        let (first, second) = (rank()?, rank()?);
+        let suited = match (chars.as_str(), first == second) {
+            ("", true) => false,
+            ("s" | "S", false) => true,
+            ("o" | "O", false) => false,
+            _ => return Err(invalid()),
+        };
+        Ok(HandClass::new(first, second, suited))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{Hand, Rank};
+
+    use super::HandClass;
+
+    #[rstest]
+    #[case("QQ", 6)]
+    #[case("AKs", 4)]
+    #[case("T9o", 12)]
+    fn has_combos(#[case] class: &str, #[case] expected_combos: usize) {
+        let class: HandClass = class.parse().unwrap();
+        let combos = class.combos();
+        assert_eq!(expected_combos, combos.len());
+        assert!(combos.iter().all(|hand| HandClass::of(hand) == class));
+    }
+
+    #[rstest]
+    #[case("AsKs", "AKs")]
+    #[case("Kd9c", "K9o")]
+    #[case("9cKd", "K9o")]
+    #[case("ThTs", "TT")]
+    fn classifies_hands(#[case] hand: &str, #[case] expected_class: &str) {
+        let hand: Hand = hand.parse().unwrap();
+        assert_eq!(expected_class, HandClass::of(&hand).to_string());
+    }
+
+    #[test]
+    fn indexes_every_class_once() {
+        let classes: Vec<HandClass> = HandClass::all().collect();
+        assert_eq!(HandClass::COUNT, classes.len());
+        for (index, class) in classes.iter().enumerate() {
+            assert_eq!(index, class.index());
+        }
+        let combos: usize = classes.iter().map(|class| class.combos().len()).sum();
+        assert_eq!(1326, combos);
+        assert_eq!(None, HandClass::from_index(HandClass::COUNT));
+    }
+
+    #[test]
+    fn orders_ranks_when_parsed() {
+        assert_eq!(
+            Ok(HandClass::new(Rank::Ace, Rank::King, false)),
+            "KAo".parse()
+        );
+    }
+
+    #[rstest]
+    #[case("AK")]
+    #[case("QQs")]
+    #[case("AKx")]
+    #[case("A")]
+    #[case("1Ks")]
+    fn rejects_invalid_classes(#[case] class: &str) {
+        assert!(class.parse::<HandClass>().is_err());
+    }
+}
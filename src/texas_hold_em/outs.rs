@@ -0,0 +1,336 @@
+//! Finds the cards that would improve a hand on the flop or turn.
+
+use std::fmt::Display;
+
+use strum::IntoEnumIterator;
+
+use crate::{Card, CardSet, Hand, Rank, Suit};
+
+use super::evaluation::{
+    evaluator::{Evaluator, HandVal},
+    hand_rank::HandRank,
+};
+
+/// A card that could come next, and the hand it would make.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NextCard {
+    pub card: Card,
+    pub value: HandVal,
+    pub hand_rank: HandRank,
+    /// Whether the card makes a better kind of hand than the player has now, that the board
+    /// alone does not make.
+    pub improves: bool,
+}
+
+/// A draw to a straight or flush.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Draw {
+    /// Four cards of the suit, including at least one hole card.
+    Flush(Suit),
+    /// Two or more ranks complete a straight, such as 9-8 on a 7-6 board.
+    OpenEndedStraight,
+    /// Only one rank completes a straight, such as 9-8 on a 6-5 board.
+    GutshotStraight,
+}
+
+/// The cards that could come next for a hand, given the board so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutsAnalysis {
+    pub value: HandVal,
+    pub hand_rank: HandRank,
+    /// Every card that has not been seen, in card order.
+    pub next_cards: Vec<NextCard>,
+    pub draws: Vec<Draw>,
+}
+
+impl OutsAnalysis {
+    /// The cards that improve the hand to a better kind of hand.
+    pub fn outs(&self) -> Vec<Card> {
+        self.cards_where(|next| next.improves)
+    }
+
+    /// The cards that improve the hand to exactly the given kind of hand.
+    pub fn outs_to(&self, hand_rank: HandRank) -> Vec<Card> {
+        self.cards_where(|next| next.improves && next.hand_rank == hand_rank)
+    }
+
+    /// The cards that give a better hand than `value`, such as the best hand at the table.
+    pub fn outs_beating(&self, value: HandVal) -> Vec<Card> {
+        self.cards_where(|next| next.value > value)
+    }
+
+    /// The chance from 0 to 1 that the next card improves the hand.
+    pub fn improvement_probability(&self) -> f64 {
+        match self.next_cards.len() {
+            0 => 0.0,
+            unseen => self.outs().len() as f64 / unseen as f64,
+        }
+    }
+
+    pub fn has_flush_draw(&self) -> bool {
+        self.draws.iter().any(|draw| matches!(draw, Draw::Flush(_)))
+    }
+
+    pub fn has_straight_draw(&self) -> bool {
+        self.draws
+            .iter()
+            .any(|draw| matches!(draw, Draw::OpenEndedStraight | Draw::GutshotStraight))
+    }
+
+    fn cards_where(&self, predicate: impl Fn(&NextCard) -> bool) -> Vec<Card> {
+        self.next_cards
+            .iter()
+            .filter(|next| predicate(next))
+            .map(|next| next.card)
+            .collect()
+    }
+}
+
+/// Why outs could not be found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutsError {
+    /// Outs are only found on the flop or turn, with three or four cards on the board.
+    BoardSize(usize),
+    DuplicateCard(Card),
+    /// The evaluator gave a value that is not a known hand.
+    UnknownValue(HandVal),
+}
+
+impl Display for OutsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutsError::BoardSize(cards) => {
+                write!(f, "The board needs three or four cards, but has {}", cards)
+            }
+            OutsError::DuplicateCard(card) => write!(f, "{} is used more than once", card),
+            OutsError::UnknownValue(value) => write!(f, "HandVal {} is not a hand", value.0),
+        }
+    }
+}
+
+impl std::error::Error for OutsError {}
+
+/// Finds how each card that could come next changes the hand, leaving out dead cards.
+pub fn analyze_outs(
+    evaluator: &dyn Evaluator,
+    hand: &Hand,
+    board: &[Card],
+    dead: CardSet,
+) -> Result<OutsAnalysis, OutsError> {
+    if !(3..=4).contains(&board.len()) {
+        return Err(OutsError::BoardSize(board.len()));
+    }
+    let mut seen = dead;
+    for card in hand.cards.iter().chain(board.iter()) {
+        if !seen.insert(*card) {
+            return Err(OutsError::DuplicateCard(*card));
+        }
+    }
+
+    let cards: Vec<Card> = hand.cards.iter().chain(board.iter()).copied().collect();
+    let value = evaluate(evaluator, &cards);
+    let hand_rank = rank_of(value)?;
+
+    let next_cards = (!seen)
+        .iter()
+        .map(|card| {
+            let mut with_card = cards.clone();
+            with_card.push(card);
+            let value = evaluate(evaluator, &with_card);
+            let next_rank = rank_of(value)?;
+            let mut board_with_card = board.to_vec();
+            board_with_card.push(card);
+            let board_rank = match board_with_card.len() {
+                5 => rank_of(evaluate(evaluator, &board_with_card))?,
+                _ => matched_rank(&board_with_card),
+            };
+            Ok(NextCard {
+                card,
+                value,
+                hand_rank: next_rank,
+                improves: next_rank > hand_rank && next_rank > board_rank,
+            })
+        })
+        .collect::<Result<Vec<NextCard>, OutsError>>()?;
+
+    Ok(OutsAnalysis {
+        value,
+        hand_rank,
+        next_cards,
+        draws: find_draws(hand, board, hand_rank),
+    })
+}
+
+fn evaluate(evaluator: &dyn Evaluator, cards: &[Card]) -> HandVal {
+    evaluator
+        .evaluate_cards(cards)
+        .expect("Cards should be five to seven distinct cards")
+}
+
+fn rank_of(value: HandVal) -> Result<HandRank, OutsError> {
+    HandRank::try_from(value).map_err(|_| OutsError::UnknownValue(value))
+}
+
+/// The kind of hand made by cards of matching rank, for boards too short to evaluate.
+fn matched_rank(cards: &[Card]) -> HandRank {
+    let mut counts: Vec<usize> = Rank::iter()
+        .map(|rank| cards.iter().filter(|card| card.rank == rank).count())
+        .collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    match (counts[0], counts[1]) {
+        (4, _) => HandRank::FourOfAKind,
+        (3, 2) => HandRank::FullHouse,
+        (3, _) => HandRank::ThreeOfAKind,
+        (2, 2) => HandRank::TwoPair,
+        (2, _) => HandRank::Pair,
+        _ => HandRank::HighCard,
+    }
+}
+
+fn find_draws(hand: &Hand, board: &[Card], hand_rank: HandRank) -> Vec<Draw> {
+    let mut draws = vec![];
+    if hand_rank < HandRank::Flush {
+        let suited_cards =
+            |suit: Suit, cards: &[Card]| cards.iter().filter(|card| card.suit == suit).count();
+        let all_cards: Vec<Card> = hand.cards.iter().chain(board.iter()).copied().collect();
+        draws.extend(
+            Suit::iter()
+                .filter(|suit| {
+                    suited_cards(*suit, &all_cards) == 4 && suited_cards(*suit, &hand.cards) > 0
+                })
+                .map(Draw::Flush),
+        );
+    }
+
+    if hand_rank < HandRank::Straight {
+        let ranks = rank_mask(hand.cards.iter().chain(board.iter()));
+        let board_ranks = rank_mask(board.iter());
+        // Ranks that make a straight the board would not make on its own
+        let completing = Rank::iter()
+            .map(|rank| 1 << rank as u16)
+            .filter(|rank| ranks & rank == 0)
+            .filter(|rank| makes_straight(ranks | rank) && !makes_straight(board_ranks | rank))
+            .count();
+        match completing {
+            0 => {}
+            1 => draws.push(Draw::GutshotStraight),
+            _ => draws.push(Draw::OpenEndedStraight),
+        }
+    }
+    draws
+}
+
+/// A bit for each rank among the cards, with twos at the lowest bit.
+fn rank_mask<'c>(cards: impl Iterator<Item = &'c Card>) -> u16 {
+    cards.fold(0, |mask, card| mask | 1 << card.rank as u16)
+}
+
+fn makes_straight(ranks: u16) -> bool {
+    // The ace also plays below the two
+    let ranks = (ranks << 1) | (ranks >> Rank::Ace as u16 & 1);
+    (0..=9).any(|low| (ranks >> low) & 0b11111 == 0b11111)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        poker::card::parse_cards,
+        texas_hold_em::evaluation::{bitmask_evaluator::BitmaskEvaluator, hand_rank::HandRank},
+        Card, CardSet, Suit,
+    };
+
+    use super::{analyze_outs, Draw, OutsAnalysis, OutsError};
+
+    fn analyze(hand: &str, board: &str) -> OutsAnalysis {
+        analyze_outs(
+            &BitmaskEvaluator,
+            &hand.parse().unwrap(),
+            &parse_cards(board).unwrap(),
+            CardSet::new(),
+        )
+        .unwrap()
+    }
+
+    fn cards(cards: &str) -> Vec<Card> {
+        let mut cards = parse_cards(cards).unwrap();
+        cards.sort_by_key(|card| card.index());
+        cards
+    }
+
+    #[test]
+    fn finds_flush_and_pair_outs() {
+        let analysis = analyze("AhKh", "Qh 7h 2c");
+
+        assert_eq!(HandRank::HighCard, analysis.hand_rank);
+        assert_eq!(vec![Draw::Flush(Suit::Heart)], analysis.draws);
+        assert_eq!(9, analysis.outs_to(HandRank::Flush).len());
+        assert_eq!(cards("Kc Kd Ks Ac Ad As"), analysis.outs_to(HandRank::Pair));
+        assert_eq!(15, analysis.outs().len());
+        assert_eq!(47, analysis.next_cards.len());
+        assert!((analysis.improvement_probability() - 15.0 / 47.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn finds_open_ended_straight_draw() {
+        let analysis = analyze("9s8d", "7c 6h 2s");
+
+        assert_eq!(vec![Draw::OpenEndedStraight], analysis.draws);
+        assert_eq!(
+            cards("5c 5d 5h 5s Tc Td Th Ts"),
+            analysis.outs_to(HandRank::Straight)
+        );
+    }
+
+    #[test]
+    fn finds_gutshot_straight_draw() {
+        let analysis = analyze("9s8d", "6c 5h 2s Kd");
+
+        assert_eq!(vec![Draw::GutshotStraight], analysis.draws);
+        assert_eq!(cards("7c 7d 7h 7s"), analysis.outs_to(HandRank::Straight));
+        assert!(analysis.has_straight_draw());
+        assert!(!analysis.has_flush_draw());
+    }
+
+    #[test]
+    fn cards_that_only_improve_the_board_are_not_outs() {
+        let analysis = analyze("AsKd", "Qh 7c 3d 2s");
+
+        // A seven pairs the board for everyone
+        assert!(!analysis.outs().contains(&"7h".parse().unwrap()));
+        assert_eq!(6, analysis.outs_to(HandRank::Pair).len());
+    }
+
+    #[test]
+    fn finds_cards_beating_another_hand() {
+        let analysis = analyze("AsKs", "Qs Js 2h 3c");
+        let sets = analyze("QdQc", "Qs Js 2h 3c");
+
+        // Any ten or spade beats the set as it stands
+        assert_eq!(12, analysis.outs_beating(sets.value).len());
+    }
+
+    #[test]
+    fn leaves_out_dead_cards() {
+        let analysis = analyze_outs(
+            &BitmaskEvaluator,
+            &"AhKh".parse().unwrap(),
+            &parse_cards("Qh 7h 2c").unwrap(),
+            "2h 3h".parse().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(45, analysis.next_cards.len());
+        assert_eq!(7, analysis.outs_to(HandRank::Flush).len());
+    }
+
+    #[test]
+    fn needs_flop_or_turn() {
+        let result = analyze_outs(
+            &BitmaskEvaluator,
+            &"AhKh".parse().unwrap(),
+            &parse_cards("Qh 7h").unwrap(),
+            CardSet::new(),
+        );
+        assert_eq!(Err(OutsError::BoardSize(2)), result);
+    }
+}
@@ -1,18 +1,166 @@
-use super::state::game_loop::GameLoop;
+use std::{collections::HashMap, fmt::Display};
+
+use crate::player::PlayerId;
+
+use super::player_view::PlayerView;
 
 pub trait PlayerDriver {
-    fn determine_move(game: &GameLoop) -> Move;
+    fn determine_move(&mut self, view: &PlayerView) -> Move;
+
+    /// Called when the move the driver chose was rejected, before it is asked for another.
+    ///
+    /// After [`MAX_ILLEGAL_MOVES`] rejected moves in a row the player is folded.
+    fn illegal_move(&mut self, _player_move: Move, _error: IllegalMoveError) {}
 }
 
-#[derive(Debug)]
+/// The number of illegal moves in a row a player can make before they are folded.
+pub const MAX_ILLEGAL_MOVES: u32 = 3;
+
+/// The driver deciding moves for each seat at the table.
+pub type PlayerDrivers = HashMap<PlayerId, Box<dyn PlayerDriver>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Move {
     Fold,
+    Check,
     Call,
-    Raise { amount: u32 },
+    /// Open the betting in a stage by betting `amount` chips.
+    Bet {
+        amount: u32,
+    },
+    /// Raise so that the player's total bet in the stage is `amount` chips.
+    RaiseTo {
+        amount: u32,
+    },
+    AllIn,
+}
+
+/// An action available to the current player, with the allowed bet sizes where relevant.
+///
+/// Bet sizes are the total chips the player will have bet in the stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LegalAction {
+    Fold,
+    Check,
+    Call { amount: u32 },
+    Bet { min: u32, max: u32 },
+    RaiseTo { min: u32, max: u32 },
+    AllIn { amount: u32 },
 }
 
-impl PartialEq for Move {
-    fn eq(&self, other: &Self) -> bool {
-        core::mem::discriminant(self) == core::mem::discriminant(other)
+impl LegalAction {
+    /// Checks a move against this action.
+    ///
+    /// Returns `None` if the move is a different kind of action, otherwise whether the move is within the allowed sizes.
+    pub fn validate(&self, player_move: &Move) -> Option<Result<(), IllegalMoveError>> {
+        match (self, player_move) {
+            (LegalAction::Fold, Move::Fold)
+            | (LegalAction::Check, Move::Check)
+            | (LegalAction::Call { .. }, Move::Call)
+            | (LegalAction::AllIn { .. }, Move::AllIn) => Some(Ok(())),
+            (LegalAction::Bet { min, max }, Move::Bet { amount })
+            | (LegalAction::RaiseTo { min, max }, Move::RaiseTo { amount }) => {
+                Some(Self::validate_amount(*amount, *min, *max))
+            }
+            _ => None,
+        }
+    }
+
+    fn validate_amount(amount: u32, min: u32, max: u32) -> Result<(), IllegalMoveError> {
+        if amount < min {
+            return Err(IllegalMoveError::BelowMinimum {
+                amount,
+                minimum: min,
+            });
+        }
+        if amount > max {
+            return Err(IllegalMoveError::AboveMaximum {
+                amount,
+                maximum: max,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Display for LegalAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LegalAction::Fold => write!(f, "Fold"),
+            LegalAction::Check => write!(f, "Check"),
+            LegalAction::Call { amount } => write!(f, "Call {}", amount),
+            LegalAction::Bet { min, max } => write!(f, "Bet {}-{}", min, max),
+            LegalAction::RaiseTo { min, max } => write!(f, "Raise to {}-{}", min, max),
+            LegalAction::AllIn { amount } => write!(f, "All-in {}", amount),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IllegalMoveError {
+    /// The move is not one of the current player's legal actions.
+    NotAvailable(Move),
+    BelowMinimum {
+        amount: u32,
+        minimum: u32,
+    },
+    AboveMaximum {
+        amount: u32,
+        maximum: u32,
+    },
+}
+
+impl Display for IllegalMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IllegalMoveError::NotAvailable(player_move) => {
+                write!(f, "{:?} is not a legal action", player_move)
+            }
+            IllegalMoveError::BelowMinimum { amount, minimum } => write!(
+                f,
+                "Bet amount ({}) is less than minimum bet ({})",
+                amount, minimum
+            ),
+            IllegalMoveError::AboveMaximum { amount, maximum } => write!(
+                f,
+                "Bet amount ({}) is greater than maximum bet ({})",
+                amount, maximum
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IllegalMoveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{IllegalMoveError, LegalAction, Move};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(LegalAction::Fold, Move::Fold)]
+    #[case(LegalAction::Check, Move::Check)]
+    #[case(LegalAction::Call { amount: 10 }, Move::Call)]
+    #[case(LegalAction::Bet { min: 10, max: 100 }, Move::Bet { amount: 10 })]
+    #[case(LegalAction::RaiseTo { min: 20, max: 100 }, Move::RaiseTo { amount: 100 })]
+    #[case(LegalAction::AllIn { amount: 100 }, Move::AllIn)]
+    fn permits_matching_moves(#[case] action: LegalAction, #[case] player_move: Move) {
+        assert_eq!(Some(Ok(())), action.validate(&player_move))
+    }
+
+    #[rstest]
+    #[case(LegalAction::Check, Move::Call)]
+    #[case(LegalAction::Call { amount: 10 }, Move::Check)]
+    #[case(LegalAction::Bet { min: 10, max: 100 }, Move::RaiseTo { amount: 20 })]
+    fn ignores_other_moves(#[case] action: LegalAction, #[case] player_move: Move) {
+        assert_eq!(None, action.validate(&player_move))
+    }
+
+    #[rstest]
+    #[case(Move::RaiseTo { amount: 15 }, IllegalMoveError::BelowMinimum { amount: 15, minimum: 20 })]
+    #[case(Move::RaiseTo { amount: 150 }, IllegalMoveError::AboveMaximum { amount: 150, maximum: 100 })]
+    fn rejects_amounts_out_of_range(#[case] player_move: Move, #[case] error: IllegalMoveError) {
+        let action = LegalAction::RaiseTo { min: 20, max: 100 };
+        assert_eq!(Some(Err(error)), action.validate(&player_move))
     }
 }
@@ -0,0 +1,37 @@
+use crate::{player::PlayerId, Card, Hand};
+
+use super::player_driver::LegalAction;
+
+/// The information available to a single player when deciding on a move.
+///
+/// Only the acting player's own hole cards are included, so drivers cannot see opponents' hands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerView {
+    pub player_id: PlayerId,
+    pub hand: Hand,
+    pub chips: u32,
+    pub chips_bet_in_stage: u32,
+    pub community_cards: Vec<Card>,
+    pub opponents: Vec<OpponentView>,
+    pub pot: u32,
+    pub minimum_bet: u32,
+    /// The player on the button, or `None` if it is a dead button.
+    pub button: Option<PlayerId>,
+    pub legal_actions: Vec<LegalAction>,
+}
+
+/// The publicly visible state of another player at the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpponentView {
+    pub id: PlayerId,
+    pub chips: u32,
+    pub chips_bet_in_stage: u32,
+    pub folded: bool,
+}
+
+impl PlayerView {
+    /// The number of chips needed to match the current minimum bet.
+    pub fn amount_to_call(&self) -> u32 {
+        self.minimum_bet.saturating_sub(self.chips_bet_in_stage)
+    }
+}
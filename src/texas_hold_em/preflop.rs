@@ -0,0 +1,229 @@
+//! The equity of every starting hand against random hands, worked out ahead of time.
+//!
+//! The table built into the library is generated by the `generate-preflop-table` binary, so
+//! preflop strength can be looked up without running a simulation. Generation runs on a single
+//! thread, so the same seed and number of iterations give the same table on any machine.
+
+use std::{fmt::Display, fs, io, path::Path, sync::OnceLock};
+
+use crate::{CardSet, Hand};
+
+use super::{
+    equity::{EquityCalculator, EquityError},
+    evaluation::evaluator::Evaluator,
+    hand_class::HandClass,
+};
+
+/// The most opponents the table has equities for, filling a ten-handed table.
+pub const MAX_OPPONENTS: usize = 9;
+
+const ENTRIES: usize = HandClass::COUNT * MAX_OPPONENTS;
+/// Equities are stored in hundredths of a percent.
+const SCALE: f64 = 100.0;
+
+static EMBEDDED: &[u8] = include_bytes!("preflop_equity.bin");
+
+/// The equity of each [`HandClass`] against one to [`MAX_OPPONENTS`] opponents holding random
+/// hands, with ties sharing the pot.
+///
+/// Equities are stored as little-endian `u16`s in hundredths of a percent, ordered by
+/// [`HandClass::index`] and then by the number of opponents, taking 3042 bytes in all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflopTable {
+    equities: Vec<u16>,
+}
+
+impl PreflopTable {
+    /// The table built into the library.
+    pub fn embedded() -> &'static PreflopTable {
+        static TABLE: OnceLock<PreflopTable> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            PreflopTable::from_bytes(EMBEDDED).expect("Embedded preflop table should be valid")
+        })
+    }
+
+    /// Samples `iterations` deals for every hand class and number of opponents.
+    ///
+    /// Each entry is sampled on one thread from its own seed, so the table depends only on
+    /// `iterations` and `seed`.
+    ///
+    /// `progress` is called with each class once its equities have been calculated.
+    pub fn generate(
+        evaluator: &dyn Evaluator,
+        iterations: u64,
+        seed: u64,
+        mut progress: impl FnMut(HandClass),
+    ) -> Result<Self, EquityError> {
+        let mut equities = Vec::with_capacity(ENTRIES);
+        for class in HandClass::all() {
+            let hand = class.combos()[0];
+            for opponents in 1..=MAX_OPPONENTS {
+                let equity = EquityCalculator::new(evaluator)
+                    .iterations(iterations)
+                    .seed(seed.wrapping_add(equities.len() as u64))
+                    .threads(1)
+                    .calculate_against_random(&hand, opponents, &[], CardSet::new())?;
+                equities.push((equity.equity() * SCALE).round() as u16);
+            }
+            progress(class);
+        }
+        Ok(PreflopTable { equities })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PreflopTableError> {
+        if bytes.len() != ENTRIES * 2 {
+            return Err(PreflopTableError::WrongSize(bytes.len()));
+        }
+        let equities: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        match equities
+            .iter()
+            .find(|equity| f64::from(**equity) > 100.0 * SCALE)
+        {
+            Some(equity) => Err(PreflopTableError::InvalidEquity(*equity)),
+            None => Ok(PreflopTable { equities }),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.equities
+            .iter()
+            .flat_map(|equity| equity.to_le_bytes())
+            .collect()
+    }
+
+    pub fn write_to(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
+    /// The equity in percent of the class against `opponents` random hands, or `None` if there
+    /// are no opponents or more than [`MAX_OPPONENTS`].
+    pub fn class_equity(&self, class: HandClass, opponents: usize) -> Option<f64> {
+        if !(1..=MAX_OPPONENTS).contains(&opponents) {
+            return None;
+        }
+        let equity = self.equities[class.index() * MAX_OPPONENTS + opponents - 1];
+        Some(f64::from(equity) / SCALE)
+    }
+
+    /// The equity in percent of the hand against `opponents` random hands.
+    pub fn equity(&self, hand: &Hand, opponents: usize) -> Option<f64> {
+        self.class_equity(HandClass::of(hand), opponents)
+    }
+}
+
+/// Looks up the equity of a hand in the embedded table, as in [`PreflopTable::equity`].
+pub fn preflop_equity(hand: &Hand, opponents: usize) -> Option<f64> {
+    PreflopTable::embedded().equity(hand, opponents)
+}
+
+/// Why a preflop table could not be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreflopTableError {
+    /// The table should have two bytes for every class and number of opponents.
+    WrongSize(usize),
+    /// An equity over 100%.
+    InvalidEquity(u16),
+}
+
+impl Display for PreflopTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreflopTableError::WrongSize(bytes) => write!(
+                f,
+                "A preflop table has {} bytes, but this one has {}",
+                ENTRIES * 2,
+                bytes
+            ),
+            PreflopTableError::InvalidEquity(equity) => {
+                write!(f, "{} is more than 100% equity", equity)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PreflopTableError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        texas_hold_em::{evaluation::bitmask_evaluator::BitmaskEvaluator, hand_class::HandClass},
+        Hand,
+    };
+
+    use super::{preflop_equity, PreflopTable, PreflopTableError, MAX_OPPONENTS};
+
+    fn class(class: &str) -> HandClass {
+        class.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case("AA", 85.2)]
+    #[case("KK", 82.4)]
+    #[case("AKs", 67.0)]
+    #[case("72o", 34.6)]
+    fn has_heads_up_equities(#[case] hand_class: &str, #[case] expected_equity: f64) {
+        let equity = PreflopTable::embedded()
+            .class_equity(class(hand_class), 1)
+            .unwrap();
+        assert!((equity - expected_equity).abs() < 1.0, "{}", equity);
+    }
+
+    #[test]
+    fn aces_are_strongest_against_any_number_of_opponents() {
+        let table = PreflopTable::embedded();
+        for opponents in 1..=MAX_OPPONENTS {
+            let aces = table.class_equity(class("AA"), opponents).unwrap();
+            assert!(HandClass::all()
+                .filter(|other| *other != class("AA"))
+                .all(|other| table.class_equity(other, opponents).unwrap() < aces));
+        }
+    }
+
+    #[test]
+    fn equity_falls_with_more_opponents() {
+        let table = PreflopTable::embedded();
+        let equities: Vec<f64> = (1..=MAX_OPPONENTS)
+            .map(|opponents| table.class_equity(class("QQ"), opponents).unwrap())
+            .collect();
+        assert!(equities.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn looks_up_hands_by_class() {
+        let hand: Hand = "Kh9h".parse().unwrap();
+        assert_eq!(
+            PreflopTable::embedded().class_equity(class("K9s"), 3),
+            preflop_equity(&hand, 3)
+        );
+        assert_eq!(None, preflop_equity(&hand, 0));
+        assert_eq!(None, preflop_equity(&hand, MAX_OPPONENTS + 1));
+    }
+
+    #[test]
+    fn generated_table_round_trips_through_bytes() {
+        let mut classes = 0;
+        let table = PreflopTable::generate(&BitmaskEvaluator, 4, 1, |_| classes += 1).unwrap();
+        assert_eq!(HandClass::COUNT, classes);
+        assert_eq!(
+            table,
+            PreflopTable::generate(&BitmaskEvaluator, 4, 1, |_| {}).unwrap()
+        );
+        assert_eq!(
+            Ok(table.clone()),
+            PreflopTable::from_bytes(&table.to_bytes())
+        );
+    }
+
+    #[test]
+    fn rejects_tables_of_the_wrong_size() {
+        assert_eq!(
+            Err(PreflopTableError::WrongSize(3)),
+            PreflopTable::from_bytes(&[0, 0, 0])
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use std::{fmt::Display, str::FromStr};
+
+use strum::IntoEnumIterator;
+
+use crate::{CardSet, Hand, Rank};
+
+use super::hand_class::HandClass;
+
+/// The hands a player could hold, each with a weight for how often they hold it.
+///
+/// Ranges are written in the usual notation, separated by commas:
+///
+/// - `QQ`, `AKs`, `AKo` for a hand class, or `AK` for both suited and offsuit
+/// - `QQ+` for a pair and every higher pair, `KTo+` for raising the kicker up to `KQo`
+/// - `A5s-A2s` or `99-66` for every class between two others
+/// - `AsKs` for a single hand
+/// - `AKs:0.5` to hold the hands only some of the time
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Range {
+    combos: Vec<WeightedCombo>,
+}
+
+/// A hand in a range, with a weight from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedCombo {
+    pub hand: Hand,
+    pub weight: f64,
+}
+
+impl Range {
+    pub fn new() -> Self {
+        Range::default()
+    }
+
+    /// Adds a hand, replacing its weight if it is already in the range.
+    pub fn add(&mut self, hand: Hand, weight: f64) {
+        let hand = normalize(hand);
+        match self.combos.iter_mut().find(|combo| combo.hand == hand) {
+            Some(combo) => combo.weight = weight,
+            None => self.combos.push(WeightedCombo { hand, weight }),
+        }
+    }
+
+    pub fn combos(&self) -> &[WeightedCombo] {
+        &self.combos
+    }
+
+    /// The number of distinct hands in the range.
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// The number of hands in the range, counting each by its weight.
+    pub fn combo_count(&self) -> f64 {
+        self.combos.iter().map(|combo| combo.weight).sum()
+    }
+
+    /// The weight of the hand, or zero if it is not in the range.
+    pub fn weight(&self, hand: &Hand) -> f64 {
+        let hand = normalize(*hand);
+        self.combos
+            .iter()
+            .find(|combo| combo.hand == hand)
+            .map_or(0.0, |combo| combo.weight)
+    }
+
+    pub fn contains(&self, hand: &Hand) -> bool {
+        self.weight(hand) > 0.0
+    }
+
+    /// The range without hands that use any of the given cards, such as the board.
+    pub fn without(&self, dead: CardSet) -> Range {
+        Range {
+            combos: self
+                .combos
+                .iter()
+                .filter(|combo| CardSet::from(combo.hand).is_disjoint(dead))
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+impl From<Hand> for Range {
+    fn from(hand: Hand) -> Self {
+        let mut range = Range::new();
+        range.add(hand, 1.0);
+        range
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Range::new();
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let (hands, weight) = match item.split_once(':') {
+                Some((hands, weight)) => (hands.trim(), parse_weight(weight.trim())?),
+                None => (item, 1.0),
+            };
+            for hand in parse_hands(hands)? {
+                range.add(hand, weight);
+            }
+        }
+        Ok(range)
+    }
+}
+
+/// Why a range could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeError {
+    InvalidHands(String),
+    /// The ends of a span like `A5s-A2s` do not share a first card and suitedness.
+    InvalidSpan(String),
+    InvalidWeight(String),
+}
+
+impl Display for ParseRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRangeError::InvalidHands(hands) => write!(f, "\"{}\" is not a hand", hands),
+            ParseRangeError::InvalidSpan(span) => {
+                write!(f, "\"{}\" does not span hands of the same kind", span)
+            }
+            ParseRangeError::InvalidWeight(weight) => {
+                write!(f, "\"{}\" is not a weight between 0 and 1", weight)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseRangeError {}
+
+/// Puts the higher card first, so each hand has one representation.
+fn normalize(hand: Hand) -> Hand {
+    let [first, second] = hand.cards;
+    match first.index() < second.index() {
+        true => Hand::new([second, first]),
+        false => hand,
+    }
+}
+
+fn parse_weight(weight: &str) -> Result<f64, ParseRangeError> {
+    weight
+        .parse::<f64>()
+        .ok()
+        .filter(|weight| (0.0..=1.0).contains(weight))
+        .ok_or_else(|| ParseRangeError::InvalidWeight(weight.to_string()))
+}
+
+fn parse_hands(hands: &str) -> Result<Vec<Hand>, ParseRangeError> {
+    if let Ok(hand) = hands.parse::<Hand>() {
+        return Ok(vec![hand]);
+    }
+
+    let classes = if let Some((first, last)) = hands.split_once('-') {
+        span(parse_pattern(first)?, parse_pattern(last)?)
+            .ok_or_else(|| ParseRangeError::InvalidSpan(hands.to_string()))?
+    } else if let Some(lowest) = hands.strip_suffix('+') {
+        and_above(parse_pattern(lowest)?)
+    } else {
+        vec![parse_pattern(hands)?]
+    };
+
+    Ok(classes
+        .iter()
+        .flat_map(Pattern::classes)
+        .flat_map(|class| class.combos())
+        .collect())
+}
+
+/// A hand class, or both the suited and offsuit classes of two ranks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pattern {
+    high: Rank,
+    low: Rank,
+    /// Whether the hands are suited, or `None` for both.
+    suited: Option<bool>,
+}
+
+impl Pattern {
+    fn classes(&self) -> Vec<HandClass> {
+        match self.suited {
+            _ if self.high == self.low => vec![HandClass::pair(self.high)],
+            Some(suited) => vec![HandClass::new(self.high, self.low, suited)],
+            None => vec![
+                HandClass::new(self.high, self.low, true),
+                HandClass::new(self.high, self.low, false),
+            ],
+        }
+    }
+
+    fn with_ranks(&self, high: Rank, low: Rank) -> Pattern {
+        Pattern { high, low, ..*self }
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Result<Pattern, ParseRangeError> {
+    let pattern = pattern.trim();
+    // Two different ranks without a suffix stand for both the suited and offsuit hands
+    let (class, suited) = match pattern.parse::<HandClass>() {
+        Ok(class) => (Ok(class), Some(class.suited)),
+        Err(_) => (format!("{}o", pattern).parse::<HandClass>(), None),
+    };
+    let class = class.map_err(|_| ParseRangeError::InvalidHands(pattern.to_string()))?;
+    Ok(Pattern {
+        high: class.high,
+        low: class.low,
+        suited,
+    })
+}
+
+/// The pair and every higher pair, or the hand with every higher kicker below the first card.
+fn and_above(lowest: Pattern) -> Vec<Pattern> {
+    let ranks = |from: Rank, to: Rank| Rank::iter().filter(move |rank| (from..to).contains(rank));
+    match lowest.high == lowest.low {
+        true => ranks(lowest.low, Rank::Ace)
+            .chain([Rank::Ace])
+            .map(|rank| lowest.with_ranks(rank, rank))
+            .collect(),
+        false => ranks(lowest.low, lowest.high)
+            .map(|kicker| lowest.with_ranks(lowest.high, kicker))
+            .collect(),
+    }
+}
+
+/// Every pattern between two others with the same shape, or `None` if they differ.
+fn span(first: Pattern, last: Pattern) -> Option<Vec<Pattern>> {
+    let (from, to) = match first.low <= last.low {
+        true => (first, last),
+        false => (last, first),
+    };
+    let in_span = |rank: &Rank| (from.low..=to.low).contains(rank);
+    match (from.high == from.low, to.high == to.low) {
+        (true, true) => Some(
+            Rank::iter()
+                .filter(in_span)
+                .map(|rank| from.with_ranks(rank, rank))
+                .collect(),
+        ),
+        (false, false) if from.high == to.high && from.suited == to.suited => Some(
+            Rank::iter()
+                .filter(in_span)
+                .map(|kicker| from.with_ranks(from.high, kicker))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{CardSet, Hand};
+
+    use super::{ParseRangeError, Range};
+
+    fn hand(hand: &str) -> Hand {
+        hand.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case("AA", 6)]
+    #[case("AKs", 4)]
+    #[case("AKo", 12)]
+    #[case("AK", 16)]
+    #[case("QQ+", 18)]
+    #[case("22+", 78)]
+    #[case("KTo+", 36)]
+    #[case("A5s-A2s", 16)]
+    #[case("A2s-A5s", 16)]
+    #[case("99-66", 24)]
+    #[case("AsKs", 1)]
+    #[case("QQ+, AKs, A5s-A2s, KTo+, 76s", 78)]
+    #[case("AKs, AsKs", 4)]
+    #[case("", 0)]
+    fn counts_combos(#[case] range: &str, #[case] expected_combos: usize) {
+        let range: Range = range.parse().unwrap();
+        assert_eq!(expected_combos, range.len());
+    }
+
+    #[test]
+    fn expands_kickers_below_first_card() {
+        let range: Range = "KTo+".parse().unwrap();
+        assert!(range.contains(&hand("KdQc")));
+        assert!(range.contains(&hand("TcKd")));
+        assert!(!range.contains(&hand("KdKc")));
+        assert!(!range.contains(&hand("Kd9c")));
+        assert!(!range.contains(&hand("KdQd")));
+    }
+
+    #[test]
+    fn weights_combos() {
+        let range: Range = "AA, AKs:0.5".parse().unwrap();
+        assert_eq!(8.0, range.combo_count());
+        assert_eq!(0.5, range.weight(&hand("KhAh")));
+        assert_eq!(1.0, range.weight(&hand("AhAd")));
+        assert_eq!(0.0, range.weight(&hand("KhKd")));
+    }
+
+    #[test]
+    fn removes_combos_blocked_by_the_board() {
+        let range: Range = "AA, KK".parse().unwrap();
+        let board: CardSet = "As Kd 2c".parse().unwrap();
+        let range = range.without(board);
+        assert_eq!(6, range.len());
+        assert!(!range.contains(&hand("AsAh")));
+        assert!(range.contains(&hand("KsKh")));
+    }
+
+    #[rstest]
+    #[case("AKx", ParseRangeError::InvalidHands("AKx".to_string()))]
+    #[case("QQ-AKs", ParseRangeError::InvalidSpan("QQ-AKs".to_string()))]
+    #[case("A5s-K2s", ParseRangeError::InvalidSpan("A5s-K2s".to_string()))]
+    #[case("AKs:2", ParseRangeError::InvalidWeight("2".to_string()))]
+    fn rejects_invalid_ranges(#[case] range: &str, #[case] expected_error: ParseRangeError) {
+        assert_eq!(Err(expected_error), range.parse::<Range>());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod advancement;
 pub mod game_loop;
 pub mod stages;
+pub mod table;
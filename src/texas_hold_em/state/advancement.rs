@@ -2,5 +2,5 @@ use super::stages::finished::Finished;
 
 pub enum Advancement<NextStage> {
     NextStage(NextStage),
-    Finished(Finished),
+    Finished(Box<Finished>),
 }
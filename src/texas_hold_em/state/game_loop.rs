@@ -1,19 +1,32 @@
 use crate::{
-    player::{Active, Folded, Player},
+    player::{Active, Folded, Player, PlayerId},
     texas_hold_em::{
-        console_player::ConsolePlayer,
-        player_driver::{Move, PlayerDriver},
+        game_config::BettingStructure,
+        player_driver::{
+            IllegalMoveError, LegalAction, Move, PlayerDriver, PlayerDrivers, MAX_ILLEGAL_MOVES,
+        },
+        player_view::{OpponentView, PlayerView},
     },
-    Pot,
+    Card, Pot,
 };
 
+/// The number of bets allowed in a fixed-limit stage, ie. a bet and three raises.
+const MAX_FIXED_LIMIT_BETS: u32 = 4;
+
 #[derive(Debug)]
 pub struct GameLoop {
     pub active_players: Vec<Player<Active>>,
     pub folded_players: Vec<Player<Folded>>,
-    pub button_index: usize,
+    /// The player on the button, or `None` if it is a dead button.
+    pub button: Option<PlayerId>,
     pub current_player_index: usize,
     pub pot: Pot,
+    pub community_cards: Vec<Card>,
+    /// The smallest amount a bet can be raised by, ie. the size of the last bet or raise.
+    pub minimum_raise: u32,
+    pub betting_structure: BettingStructure,
+    /// The number of bets and raises made in the stage, including the big blind.
+    pub bets_in_stage: u32,
 }
 
 #[derive(Debug)]
@@ -30,16 +43,35 @@ enum MoveOutcome {
 }
 
 impl GameLoop {
-    pub fn do_stage(mut self) -> StageOutcome {
-        let mut move_outcome = MoveOutcome::NextMove;
+    pub fn do_stage(mut self, drivers: &mut PlayerDrivers) -> StageOutcome {
+        self.current_player_index = self.next_player_to_act(self.current_player_index);
+        let mut move_outcome = match self.is_stage_complete() {
+            true => MoveOutcome::StageFinished,
+            false => MoveOutcome::NextMove,
+        };
+        let mut illegal_moves = 0;
         while move_outcome == MoveOutcome::NextMove {
-            let player_move = self.determine_move();
-            move_outcome = self.do_move(player_move);
+            let player_move = match illegal_moves < MAX_ILLEGAL_MOVES {
+                true => self.determine_move(drivers),
+                false => Move::Fold,
+            };
+            match self.do_move(player_move) {
+                Ok(outcome) => {
+                    move_outcome = outcome;
+                    illegal_moves = 0;
+                }
+                Err(err) => {
+                    illegal_moves += 1;
+                    self.current_driver(drivers).illegal_move(player_move, err);
+                }
+            }
         }
 
         self.pot.minimum_bet = 0;
         for player in self.active_players.iter_mut() {
             player.state.chips_bet_in_stage = 0;
+            player.state.has_acted = false;
+            player.state.raise_closed = false;
         }
 
         match move_outcome {
@@ -49,41 +81,154 @@ impl GameLoop {
         }
     }
 
-    fn determine_move(&self) -> Move {
-        let player_move = ConsolePlayer::determine_move(self);
-        println!(
-            "Player {:?} chose {:?}",
-            self.active_players[self.current_player_index].id, player_move
-        );
+    fn determine_move(&self, drivers: &mut PlayerDrivers) -> Move {
+        let player_id = self.active_players[self.current_player_index].id;
+        let player_move = self
+            .current_driver(drivers)
+            .determine_move(&self.player_view());
+        println!("Player {:?} chose {:?}", player_id, player_move);
 
         player_move
     }
 
-    fn do_move(&mut self, player_move: Move) -> MoveOutcome {
-        let mut advance_stage = false;
-        match player_move {
-            Move::Fold => {
-                self.handle_fold();
-                advance_stage = self.current_player_index == self.button_index;
+    fn current_driver<'d>(&self, drivers: &'d mut PlayerDrivers) -> &'d mut dyn PlayerDriver {
+        let player_id = self.active_players[self.current_player_index].id;
+        drivers
+            .get_mut(&player_id)
+            .unwrap_or_else(|| panic!("No driver registered for player {}", player_id))
+            .as_mut()
+    }
+
+    /// Builds the view of the game available to the current player.
+    pub fn player_view(&self) -> PlayerView {
+        let player = &self.active_players[self.current_player_index];
+        let active_opponents = self
+            .active_players
+            .iter()
+            .filter(|opponent| opponent.id != player.id)
+            .map(|opponent| OpponentView {
+                id: opponent.id,
+                chips: opponent.chips,
+                chips_bet_in_stage: opponent.state.chips_bet_in_stage,
+                folded: false,
+            });
+        let folded_opponents = self.folded_players.iter().map(|opponent| OpponentView {
+            id: opponent.id,
+            chips: opponent.chips,
+            chips_bet_in_stage: 0,
+            folded: true,
+        });
+
+        PlayerView {
+            player_id: player.id,
+            hand: player.hand,
+            chips: player.chips,
+            chips_bet_in_stage: player.state.chips_bet_in_stage,
+            community_cards: self.community_cards.clone(),
+            opponents: active_opponents.chain(folded_opponents).collect(),
+            pot: self.pot.chips,
+            minimum_bet: self.pot.minimum_bet,
+            button: self.button,
+            legal_actions: self.legal_actions(),
+        }
+    }
+
+    /// The actions the current player is allowed to take.
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        let player = &self.active_players[self.current_player_index];
+        let bet_in_stage = player.state.chips_bet_in_stage;
+        let amount_to_call = self.pot.minimum_bet.saturating_sub(bet_in_stage);
+        let stack = bet_in_stage + player.chips;
+        let bet_limit = if player.state.raise_closed {
+            None
+        } else {
+            self.bet_limit(amount_to_call)
+        };
+
+        let mut actions = vec![LegalAction::Fold];
+        if amount_to_call == 0 {
+            actions.push(LegalAction::Check);
+        } else if player.chips >= amount_to_call {
+            actions.push(LegalAction::Call {
+                amount: amount_to_call,
+            });
+        }
+
+        if let Some(max_bet) = bet_limit.map(|limit| limit.min(stack)) {
+            if self.pot.minimum_bet == 0 {
+                if max_bet >= self.minimum_raise {
+                    actions.push(LegalAction::Bet {
+                        min: self.minimum_raise,
+                        max: max_bet,
+                    });
+                }
+            } else {
+                let min_raise = self.pot.minimum_bet + self.minimum_raise;
+                if max_bet >= min_raise {
+                    actions.push(LegalAction::RaiseTo {
+                        min: min_raise,
+                        max: max_bet,
+                    });
+                }
             }
-            Move::Call => {
-                self.handle_call();
-                advance_stage = self.current_player_index == self.button_index;
+        }
+
+        let within_limit = bet_limit.is_some_and(|limit| stack <= limit);
+        if player.chips > 0 && (player.chips <= amount_to_call || within_limit) {
+            actions.push(LegalAction::AllIn {
+                amount: player.chips,
+            });
+        }
+
+        actions
+    }
+
+    /// The most a player can have bet in the stage under the betting structure, or `None` if no more raises are allowed.
+    fn bet_limit(&self, amount_to_call: u32) -> Option<u32> {
+        match self.betting_structure {
+            BettingStructure::NoLimit => Some(u32::MAX),
+            BettingStructure::PotLimit => {
+                Some(self.pot.minimum_bet + self.pot.chips + amount_to_call)
             }
-            Move::Raise { amount } => self.handle_raise(amount),
+            BettingStructure::FixedLimit if self.bets_in_stage >= MAX_FIXED_LIMIT_BETS => None,
+            BettingStructure::FixedLimit => Some(self.pot.minimum_bet + self.minimum_raise),
         }
+    }
 
-        if self.active_players.len() == 1 {
-            return MoveOutcome::RoundFinished;
+    /// Checks that the current player is allowed to make a move.
+    pub fn validate_move(&self, player_move: &Move) -> Result<(), IllegalMoveError> {
+        self.legal_actions()
+            .iter()
+            .find_map(|action| action.validate(player_move))
+            .unwrap_or(Err(IllegalMoveError::NotAvailable(*player_move)))
+    }
+
+    fn do_move(&mut self, player_move: Move) -> Result<MoveOutcome, IllegalMoveError> {
+        self.validate_move(&player_move)?;
+
+        let bet_in_stage = self.active_players[self.current_player_index]
+            .state
+            .chips_bet_in_stage;
+        match player_move {
+            Move::Fold => self.handle_fold(),
+            Move::Check => self.handle_bet(Some(0)),
+            Move::Call => self.handle_bet(Some(self.pot.minimum_bet - bet_in_stage)),
+            Move::Bet { amount } | Move::RaiseTo { amount } => {
+                self.handle_bet(Some(amount - bet_in_stage))
+            }
+            Move::AllIn => self.handle_bet(None),
         }
 
-        self.advance_player(player_move);
+        if self.active_players.len() == 1 {
+            return Ok(MoveOutcome::RoundFinished);
+        }
 
-        if advance_stage {
-            return MoveOutcome::StageFinished;
+        if self.is_stage_complete() {
+            return Ok(MoveOutcome::StageFinished);
         }
 
-        MoveOutcome::NextMove
+        self.advance_player(player_move);
+        Ok(MoveOutcome::NextMove)
     }
 
     fn handle_fold(&mut self) {
@@ -92,55 +237,455 @@ impl GameLoop {
         self.folded_players.push(folded_player);
     }
 
-    fn handle_call(&mut self) {
+    /// Puts the current player's chips into the pot, or all of their chips if no amount is given.
+    ///
+    /// An all-in raise smaller than the minimum raise makes the others call the difference, but
+    /// doesn't let players who have already acted raise again.
+    fn handle_bet(&mut self, amount: Option<u32>) {
+        let previous_bet = self.pot.minimum_bet;
         let current_player = &mut self.active_players[self.current_player_index];
-        let amount_to_bet = self.pot.minimum_bet - current_player.state.chips_bet_in_stage;
-        match current_player.bet(amount_to_bet, &mut self.pot) {
-            Ok(_) => (),
-            Err(err) => {
-                println!("Error performing player call: {}. Folding instead", err);
-                self.handle_fold();
-            }
+        match amount {
+            Some(amount) => current_player
+                .bet(amount, &mut self.pot)
+                .expect("Validated bet should succeed"),
+            None => _ = current_player.all_in(&mut self.pot),
         }
-    }
+        current_player.state.has_acted = true;
 
-    fn handle_raise(&mut self, amount: u32) {
-        let current_player = &mut self.active_players[self.current_player_index];
-        match current_player.bet(amount, &mut self.pot) {
-            Ok(_) => println!("Minimum bet: {}", amount),
-            Err(err) => {
-                println!("Error performing player raise: {}. Folding instead", err);
-                self.handle_fold();
+        if self.pot.minimum_bet > previous_bet {
+            let raise = self.pot.minimum_bet - previous_bet;
+            let full_raise = previous_bet == 0 || raise >= self.minimum_raise;
+            if full_raise {
+                self.bets_in_stage += 1;
+                self.minimum_raise = self.minimum_raise.max(raise);
             }
+            let current_player_id = current_player.id;
+            self.active_players
+                .iter_mut()
+                .filter(|player| player.id != current_player_id)
+                .for_each(|player| {
+                    player.state.raise_closed =
+                        !full_raise && (player.state.raise_closed || player.state.has_acted);
+                    player.state.has_acted = false;
+                });
         }
     }
 
+    /// Whether every player still able to bet has acted and matched the highest bet.
+    fn is_stage_complete(&self) -> bool {
+        let players_to_act = self
+            .active_players
+            .iter()
+            .filter(|player| player.chips > 0)
+            .collect::<Vec<_>>();
+        let bets_matched = players_to_act
+            .iter()
+            .all(|player| player.state.chips_bet_in_stage >= self.pot.minimum_bet);
+        let everyone_acted =
+            players_to_act.len() <= 1 || players_to_act.iter().all(|player| player.state.has_acted);
+
+        bets_matched && everyone_acted
+    }
+
     fn advance_player(&mut self, player_move: Move) {
-        match player_move {
-            Move::Fold => {
-                if self.current_player_index <= self.button_index {
-                    self.button_index = match self.button_index {
-                        0 => self.active_players.len() - 1,
-                        _ => self.button_index - 1,
-                    }
-                }
+        // Folded players are removed, so the next player has moved into the current index
+        let next_index = match player_move {
+            Move::Fold => self.current_player_index,
+            _ => self.current_player_index + 1,
+        };
 
-                if self.current_player_index >= self.active_players.len() {
-                    self.current_player_index = 0
-                }
-            }
-            Move::Call => {
-                self.current_player_index =
-                    (self.current_player_index + 1) % self.active_players.len()
-            }
-            Move::Raise { amount: _ } => {
-                self.button_index = match self.current_player_index {
-                    0 => self.active_players.len() - 1,
-                    _ => self.current_player_index - 1,
-                };
-                self.current_player_index =
-                    (self.current_player_index + 1) % self.active_players.len()
-            }
+        self.current_player_index = self.next_player_to_act(next_index);
+    }
+
+    /// Finds the first player from `index` onwards who still has chips to bet.
+    fn next_player_to_act(&self, index: usize) -> usize {
+        let player_count = self.active_players.len();
+        (0..player_count)
+            .map(|offset| (index + offset) % player_count)
+            .find(|&i| self.active_players[i].chips > 0)
+            .unwrap_or(index % player_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        player::{Active, Player, PlayerId},
+        texas_hold_em::{
+            player_driver::{
+                IllegalMoveError, LegalAction, Move, PlayerDriver, PlayerDrivers, MAX_ILLEGAL_MOVES,
+            },
+            player_view::PlayerView,
+        },
+        Deck, Hand, Pot,
+    };
+
+    use super::{BettingStructure, GameLoop, MoveOutcome, StageOutcome};
+    use rstest::rstest;
+
+    struct ScriptedPlayer {
+        moves: Vec<Move>,
+        decisions: Rc<RefCell<Vec<PlayerId>>>,
+    }
+
+    impl PlayerDriver for ScriptedPlayer {
+        fn determine_move(&mut self, view: &PlayerView) -> Move {
+            self.decisions.borrow_mut().push(view.player_id);
+            self.moves.remove(0)
+        }
+    }
+
+    fn create_game_loop(players: u8) -> GameLoop {
+        let mut deck = Deck::new();
+        let active_players = (1..=players)
+            .map(|id| {
+                let hand = Hand::new([deck.draw().unwrap(), deck.draw().unwrap()]);
+                Player::<Active>::new(PlayerId(id), hand, 100)
+            })
+            .collect::<Vec<_>>();
+        GameLoop {
+            button: active_players.last().map(|player| player.id),
+            active_players,
+            folded_players: vec![],
+            current_player_index: 0,
+            pot: Pot::default(),
+            community_cards: vec![],
+            minimum_raise: 10,
+            betting_structure: BettingStructure::NoLimit,
+            bets_in_stage: 0,
+        }
+    }
+
+    fn create_drivers(
+        scripts: Vec<(u8, Vec<Move>)>,
+        decisions: &Rc<RefCell<Vec<PlayerId>>>,
+    ) -> PlayerDrivers {
+        scripts
+            .into_iter()
+            .map(|(id, moves)| {
+                let driver: Box<dyn PlayerDriver> = Box::new(ScriptedPlayer {
+                    moves,
+                    decisions: Rc::clone(decisions),
+                });
+                (PlayerId(id), driver)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn asks_the_driver_of_the_current_player() {
+        let decisions = Rc::new(RefCell::new(vec![]));
+        let mut drivers = create_drivers(
+            vec![
+                (1, vec![Move::Check]),
+                (2, vec![Move::Check]),
+                (3, vec![Move::Check]),
+            ],
+            &decisions,
+        );
+
+        let outcome = create_game_loop(3).do_stage(&mut drivers);
+
+        assert!(matches!(outcome, StageOutcome::NextStage(_)));
+        assert_eq!(
+            vec![PlayerId(1), PlayerId(2), PlayerId(3)],
+            *decisions.borrow()
+        );
+    }
+
+    #[test]
+    fn drivers_keep_their_state_between_decisions() {
+        let decisions = Rc::new(RefCell::new(vec![]));
+        let mut drivers = create_drivers(
+            vec![
+                (1, vec![Move::Check, Move::Fold]),
+                (2, vec![Move::Bet { amount: 10 }]),
+            ],
+            &decisions,
+        );
+
+        let outcome = create_game_loop(2).do_stage(&mut drivers);
+
+        assert!(matches!(outcome, StageOutcome::Finished(_)));
+        assert_eq!(
+            vec![PlayerId(1), PlayerId(2), PlayerId(1)],
+            *decisions.borrow()
+        );
+    }
+
+    #[test]
+    fn illegal_moves_are_retried() {
+        let decisions = Rc::new(RefCell::new(vec![]));
+        let mut drivers = create_drivers(
+            vec![(1, vec![Move::Call, Move::Check]), (2, vec![Move::Check])],
+            &decisions,
+        );
+
+        let outcome = create_game_loop(2).do_stage(&mut drivers);
+
+        assert!(matches!(outcome, StageOutcome::NextStage(_)));
+        assert_eq!(
+            vec![PlayerId(1), PlayerId(1), PlayerId(2)],
+            *decisions.borrow()
+        );
+    }
+
+    /// Keeps choosing the same move, and records why it was rejected.
+    struct StubbornPlayer {
+        player_move: Move,
+        errors: Rc<RefCell<Vec<IllegalMoveError>>>,
+    }
+
+    impl PlayerDriver for StubbornPlayer {
+        fn determine_move(&mut self, _view: &PlayerView) -> Move {
+            self.player_move
         }
+
+        fn illegal_move(&mut self, _player_move: Move, error: IllegalMoveError) {
+            self.errors.borrow_mut().push(error);
+        }
+    }
+
+    #[test]
+    fn folds_players_who_keep_making_illegal_moves() {
+        let errors = Rc::new(RefCell::new(vec![]));
+        let mut drivers = create_drivers(vec![(2, vec![Move::Check])], &Rc::default());
+        drivers.insert(
+            PlayerId(1),
+            Box::new(StubbornPlayer {
+                player_move: Move::Call,
+                errors: Rc::clone(&errors),
+            }),
+        );
+
+        let outcome = create_game_loop(2).do_stage(&mut drivers);
+
+        let StageOutcome::Finished(game_loop) = outcome else {
+            panic!("Round should finish when the only other player is folded");
+        };
+        assert_eq!(PlayerId(1), game_loop.folded_players[0].id);
+        assert_eq!(
+            vec![IllegalMoveError::NotAvailable(Move::Call); MAX_ILLEGAL_MOVES as usize],
+            *errors.borrow()
+        );
+    }
+
+    #[test]
+    fn player_view_only_contains_own_hand() {
+        let mut game_loop = create_game_loop(3);
+        game_loop.current_player_index = 1;
+        game_loop.pot.minimum_bet = 10;
+        game_loop.active_players[0].state.chips_bet_in_stage = 10;
+
+        let view = game_loop.player_view();
+
+        assert_eq!(PlayerId(2), view.player_id);
+        assert_eq!(game_loop.active_players[1].hand, view.hand);
+        assert_eq!(10, view.amount_to_call());
+        assert_eq!(2, view.opponents.len());
+        assert!(view
+            .opponents
+            .iter()
+            .all(|opponent| opponent.id != PlayerId(2)));
+        assert_eq!(10, view.opponents[0].chips_bet_in_stage);
+    }
+
+    #[test]
+    fn can_check_or_bet_when_there_is_no_bet() {
+        let game_loop = create_game_loop(2);
+
+        assert_eq!(
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Bet { min: 10, max: 100 },
+                LegalAction::AllIn { amount: 100 },
+            ],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn can_call_or_raise_when_facing_a_bet() {
+        let mut game_loop = create_game_loop(2);
+        game_loop.do_move(Move::Bet { amount: 20 }).unwrap();
+
+        assert_eq!(
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: 20 },
+                LegalAction::RaiseTo { min: 40, max: 100 },
+                LegalAction::AllIn { amount: 100 },
+            ],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn cannot_call_without_enough_chips() {
+        let mut game_loop = create_game_loop(2);
+        game_loop.pot.minimum_bet = 150;
+
+        assert_eq!(
+            vec![LegalAction::Fold, LegalAction::AllIn { amount: 100 }],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn short_stacked_player_can_go_all_in() {
+        let mut game_loop = create_game_loop(3);
+        game_loop.active_players[1].chips = 30;
+        game_loop.do_move(Move::Bet { amount: 50 }).unwrap();
+
+        assert_eq!(Ok(MoveOutcome::NextMove), game_loop.do_move(Move::AllIn));
+        assert!(game_loop.active_players[1].is_all_in());
+        assert_eq!(50, game_loop.pot.minimum_bet);
+        assert_eq!(80, game_loop.pot.chips);
+        assert_eq!(
+            Ok(MoveOutcome::StageFinished),
+            game_loop.do_move(Move::Call)
+        );
+    }
+
+    #[test]
+    fn skips_players_who_are_all_in() {
+        let mut game_loop = create_game_loop(3);
+        game_loop.do_move(Move::AllIn).unwrap();
+        game_loop.do_move(Move::Call).unwrap();
+        game_loop.do_move(Move::Call).unwrap();
+
+        let mut next_stage = create_game_loop(3);
+        next_stage.active_players = game_loop.active_players;
+        let decisions = Rc::new(RefCell::new(vec![]));
+        let mut drivers = create_drivers(vec![], &decisions);
+
+        let outcome = next_stage.do_stage(&mut drivers);
+
+        assert!(matches!(outcome, StageOutcome::NextStage(_)));
+        assert!(decisions.borrow().is_empty());
+    }
+
+    #[rstest]
+    #[case(Move::Call, IllegalMoveError::NotAvailable(Move::Call))]
+    #[case(Move::RaiseTo { amount: 20 }, IllegalMoveError::NotAvailable(Move::RaiseTo { amount: 20 }))]
+    #[case(Move::Bet { amount: 5 }, IllegalMoveError::BelowMinimum { amount: 5, minimum: 10 })]
+    #[case(Move::Bet { amount: 500 }, IllegalMoveError::AboveMaximum { amount: 500, maximum: 100 })]
+    fn rejects_illegal_moves(#[case] player_move: Move, #[case] error: IllegalMoveError) {
+        let mut game_loop = create_game_loop(2);
+
+        assert_eq!(Err(error), game_loop.do_move(player_move));
+        assert_eq!(2, game_loop.active_players.len());
+        assert_eq!(0, game_loop.current_player_index);
+    }
+
+    #[test]
+    fn raising_to_sets_the_total_bet() {
+        let mut game_loop = create_game_loop(2);
+        game_loop.do_move(Move::Bet { amount: 20 }).unwrap();
+        game_loop.do_move(Move::RaiseTo { amount: 50 }).unwrap();
+
+        assert_eq!(50, game_loop.pot.minimum_bet);
+        assert_eq!(30, game_loop.minimum_raise);
+        assert_eq!(50, game_loop.active_players[1].state.chips_bet_in_stage);
+        assert_eq!(70, game_loop.pot.chips);
+    }
+
+    #[test]
+    fn raising_reopens_the_action() {
+        let mut game_loop = create_game_loop(3);
+        assert_eq!(Ok(MoveOutcome::NextMove), game_loop.do_move(Move::Check));
+        assert_eq!(
+            Ok(MoveOutcome::NextMove),
+            game_loop.do_move(Move::Bet { amount: 10 })
+        );
+        assert_eq!(Ok(MoveOutcome::NextMove), game_loop.do_move(Move::Call));
+        assert_eq!(0, game_loop.current_player_index);
+        assert_eq!(
+            Ok(MoveOutcome::StageFinished),
+            game_loop.do_move(Move::Call)
+        );
+    }
+
+    #[test]
+    fn folding_to_one_player_finishes_the_round() {
+        let mut game_loop = create_game_loop(2);
+
+        assert_eq!(
+            Ok(MoveOutcome::RoundFinished),
+            game_loop.do_move(Move::Fold)
+        );
+    }
+
+    #[test]
+    fn pot_limit_caps_raises_at_the_pot() {
+        let mut game_loop = create_game_loop(3);
+        game_loop.betting_structure = BettingStructure::PotLimit;
+        game_loop.pot.add_chips(PlayerId(3), 30);
+        game_loop.do_move(Move::Bet { amount: 10 }).unwrap();
+
+        // Calling the 10 makes a pot of 50, so the player can raise by 50
+        assert_eq!(
+            vec![
+                LegalAction::Fold,
+                LegalAction::Call { amount: 10 },
+                LegalAction::RaiseTo { min: 20, max: 60 },
+            ],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn fixed_limit_bets_are_a_fixed_size() {
+        let mut game_loop = create_game_loop(2);
+        game_loop.betting_structure = BettingStructure::FixedLimit;
+
+        assert_eq!(
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Bet { min: 10, max: 10 },
+            ],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn fixed_limit_caps_the_number_of_raises() {
+        let mut game_loop = create_game_loop(2);
+        game_loop.betting_structure = BettingStructure::FixedLimit;
+        game_loop.do_move(Move::Bet { amount: 10 }).unwrap();
+        game_loop.do_move(Move::RaiseTo { amount: 20 }).unwrap();
+        game_loop.do_move(Move::RaiseTo { amount: 30 }).unwrap();
+        game_loop.do_move(Move::RaiseTo { amount: 40 }).unwrap();
+
+        assert_eq!(
+            vec![LegalAction::Fold, LegalAction::Call { amount: 10 }],
+            game_loop.legal_actions()
+        );
+    }
+
+    #[test]
+    fn short_all_in_does_not_reopen_the_betting() {
+        let mut game_loop = create_game_loop(3);
+        game_loop.active_players[2].chips = 50;
+        game_loop.do_move(Move::Bet { amount: 40 }).unwrap();
+        game_loop.do_move(Move::Call).unwrap();
+        game_loop.do_move(Move::AllIn).unwrap();
+
+        assert_eq!(50, game_loop.pot.minimum_bet);
+        assert_eq!(1, game_loop.bets_in_stage);
+        assert_eq!(40, game_loop.minimum_raise);
+        assert_eq!(
+            vec![LegalAction::Fold, LegalAction::Call { amount: 10 }],
+            game_loop.legal_actions()
+        );
+        assert_eq!(Ok(MoveOutcome::NextMove), game_loop.do_move(Move::Call));
+        assert_eq!(
+            Ok(MoveOutcome::StageFinished),
+            game_loop.do_move(Move::Call)
+        );
     }
 }
@@ -2,34 +2,56 @@ use std::fmt::Display;
 
 use crate::{
     player::{Active, Folded, Player},
+    poker::pot::SidePot,
+    texas_hold_em::state::table::Table,
     Deck, Pot,
 };
 
-use super::pre_round::PreRound;
+use super::{pre_round::PreRound, showdown::MadeHand};
 
 #[derive(Debug)]
 pub struct Finished {
     pub active_players: Vec<Player<Active>>,
     pub folded_players: Vec<Player<Folded>>,
-    pub pot: Pot,
+    /// The pots to pay out, each split between its eligible players.
+    pub pots: Vec<SidePot>,
+    /// The hands shown down, empty if everyone else folded.
+    pub made_hands: Vec<MadeHand>,
     pub deck: Deck,
+    pub table: Table,
 }
 
 impl Finished {
     pub fn payout(self) -> PreRound {
         self.print_stage_info();
-        let mut winners = self.active_players.clone();
-        let mut pot = self.pot;
-        pot.deal_winnings(winners.iter_mut().collect());
-        let players = self
+        let mut players = self.active_players.clone();
+        players.sort_by_key(|player| self.table.distance_from_button(player.id));
+        for pot in self.pots.iter() {
+            let winners = players
+                .iter_mut()
+                .filter(|player| pot.eligible_players.contains(&player.id))
+                .collect();
+            pot.deal_winnings(winners);
+        }
+
+        let mut table = self.table;
+        let mut players = self
             .folded_players
             .into_iter()
-            .chain(Finished::fold_active_players(winners).into_iter())
-            .collect();
+            .chain(Finished::fold_active_players(players))
+            .collect::<Vec<Player<Folded>>>();
+        players.sort_by_key(|player| table.seat_of(player.id));
+        for player in players.iter().filter(|player| player.chips == 0) {
+            println!("{} busted out", table.player_name(player.id));
+            table.bust_player(player.id);
+        }
+        players.retain(|player| player.chips > 0);
+
         PreRound {
             players,
-            pot,
+            pot: Pot::default(),
             deck: self.deck,
+            table,
         }
     }
 
@@ -42,15 +64,24 @@ impl Finished {
 
     fn print_stage_info(&self) {
         println!("{}", self);
-        println!(
-            "Players {} split a pot of {}",
-            self.active_players
+        for pot in self.pots.iter() {
+            let winning_hand = self
+                .made_hands
                 .iter()
-                .map(|player| player.id.to_string())
-                .reduce(|acc, id| format!("{acc}, {id}"))
-                .unwrap_or_default(),
-            self.pot.chips
-        )
+                .find(|made_hand| pot.eligible_players.contains(&made_hand.player));
+            println!(
+                "Players {} split a pot of {}{}",
+                pot.eligible_players
+                    .iter()
+                    .map(|&id| self.table.player_name(id).to_string())
+                    .reduce(|acc, id| format!("{acc}, {id}"))
+                    .unwrap_or_default(),
+                pot.chips,
+                winning_hand
+                    .map(|made_hand| format!(" with {}", made_hand))
+                    .unwrap_or_default()
+            )
+        }
     }
 }
 
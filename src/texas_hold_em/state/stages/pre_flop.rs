@@ -3,9 +3,11 @@ use std::fmt::Display;
 use super::super::{
     advancement::Advancement,
     game_loop::{GameLoop, StageOutcome},
+    table::Table,
 };
 use crate::{
-    player::{Active, Folded, Player},
+    player::{Active, Folded, Player, PlayerId},
+    texas_hold_em::player_driver::PlayerDrivers,
     Deck, Pot,
 };
 
@@ -17,10 +19,11 @@ pub struct PreFlop {
     pub folded_players: Vec<Player<Folded>>,
     pub pot: Pot,
     pub deck: Deck,
+    pub table: Table,
 }
 
 impl PreFlop {
-    pub fn advance(mut self) -> Advancement<Flop> {
+    pub fn advance(mut self, drivers: &mut PlayerDrivers) -> Advancement<Flop> {
         self.print_stage_info();
         let mut deck = self.deck;
         let cards = [
@@ -30,9 +33,9 @@ impl PreFlop {
         ];
 
         self.deck = deck;
-        self.pot.minimum_bet = 10;
-        let game_loop = self.create_game_loop();
-        let stage_outcome = game_loop.do_stage();
+        let big_blind_index = self.post_forced_bets();
+        let game_loop = self.create_game_loop(big_blind_index);
+        let stage_outcome = game_loop.do_stage(drivers);
 
         match stage_outcome {
             StageOutcome::NextStage(game_loop) => Advancement::NextStage(Flop {
@@ -40,34 +43,78 @@ impl PreFlop {
                 folded_players: game_loop.folded_players,
                 pot: game_loop.pot,
                 deck: self.deck,
+                table: self.table,
                 cards,
             }),
-            StageOutcome::Finished(game_loop) => Advancement::Finished(Finished {
+            StageOutcome::Finished(game_loop) => Advancement::Finished(Box::new(Finished {
+                pots: game_loop.pot.side_pots(&game_loop.active_players),
                 active_players: game_loop.active_players,
                 folded_players: game_loop.folded_players,
-                pot: game_loop.pot,
+                made_hands: vec![],
                 deck: self.deck,
-            }),
+                table: self.table,
+            })),
+        }
+    }
+
+    /// Posts the antes and blinds for the round, returning the index of the big blind.
+    ///
+    /// A big blind ante is posted after the big blind, so a short-stacked big blind posts as much
+    /// of the blind as they can before any ante.
+    fn post_forced_bets(&mut self) -> usize {
+        let blinds = self.table.blinds;
+        let small_blind_index = self.index_of(self.table.small_blind_player());
+        let big_blind_index = self
+            .index_of(self.table.big_blind_player())
+            .expect("Big blind should always be dealt in");
+
+        if !blinds.big_blind_ante {
+            for player in self.active_players.iter_mut() {
+                player.post_ante(blinds.ante, &mut self.pot);
+            }
         }
+
+        // A busted small blind leaves a dead small blind, which nobody posts
+        if let Some(small_blind_index) = small_blind_index {
+            self.active_players[small_blind_index].post_blind(blinds.small_blind, &mut self.pot);
+        }
+        self.active_players[big_blind_index].post_blind(blinds.big_blind, &mut self.pot);
+        self.pot.minimum_bet = self.pot.minimum_bet.max(blinds.big_blind);
+        if blinds.big_blind_ante {
+            self.active_players[big_blind_index].post_ante(blinds.ante, &mut self.pot);
+        }
+
+        big_blind_index
+    }
+
+    fn index_of(&self, player: Option<PlayerId>) -> Option<usize> {
+        self.active_players
+            .iter()
+            .position(|active| Some(active.id) == player)
     }
 
-    fn create_game_loop(&self) -> GameLoop {
+    fn create_game_loop(&self, big_blind_index: usize) -> GameLoop {
         let active_players = self.active_players.clone();
         let folded_players = self.folded_players.clone();
-        let button_index = active_players.len() - 1; // TODO Bump each round
-        let current_player_index = 0;
-        let pot = self.pot;
+        let button = self.table.button_player();
+        let current_player_index = (big_blind_index + 1) % active_players.len();
+        let pot = self.pot.clone();
         GameLoop {
             active_players,
             folded_players,
-            button_index,
+            button,
             current_player_index,
             pot,
+            minimum_raise: self.table.small_bet(),
+            betting_structure: self.table.config.betting_structure,
+            bets_in_stage: 1,
+            community_cards: vec![],
         }
     }
 
     fn print_stage_info(&self) {
         println!("{}", self);
+        println!("{}", self.table.blinds);
         for player in self.active_players.iter() {
             println!("{}", player)
         }
@@ -84,3 +131,119 @@ impl Display for PreFlop {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        player::{Active, Player, PlayerId},
+        texas_hold_em::{game_config::GameConfig, state::table::Table},
+        BlindLevel, BlindStructure, Deck, Hand, Pot,
+    };
+
+    use super::PreFlop;
+
+    fn create_pre_flop(players: u8, blinds: BlindLevel) -> PreFlop {
+        let mut deck = Deck::new();
+        let active_players = (1..=players)
+            .map(|id| {
+                let hand = Hand::new([deck.draw().unwrap(), deck.draw().unwrap()]);
+                Player::<Active>::new(PlayerId(id), hand, 100)
+            })
+            .collect::<Vec<Player<Active>>>();
+        let config = GameConfig::builder()
+            .players(players)
+            .blind_structure(BlindStructure::fixed(blinds).unwrap())
+            .build()
+            .unwrap();
+        let mut table = Table::new(config);
+        table.start_hand();
+        PreFlop {
+            active_players,
+            folded_players: vec![],
+            pot: Pot::default(),
+            deck,
+            table,
+        }
+    }
+
+    #[test]
+    fn posts_blinds_left_of_the_button() {
+        let mut pre_flop = create_pre_flop(4, BlindLevel::new(5, 10));
+
+        let big_blind_index = pre_flop.post_forced_bets();
+
+        assert_eq!(1, big_blind_index);
+        assert_eq!(5, pre_flop.active_players[0].state.chips_bet_in_stage);
+        assert_eq!(10, pre_flop.active_players[1].state.chips_bet_in_stage);
+        assert_eq!(15, pre_flop.pot.chips);
+        assert_eq!(10, pre_flop.pot.minimum_bet);
+        assert_eq!(
+            2,
+            pre_flop
+                .create_game_loop(big_blind_index)
+                .current_player_index
+        );
+    }
+
+    #[test]
+    fn button_posts_small_blind_heads_up() {
+        let mut pre_flop = create_pre_flop(2, BlindLevel::new(5, 10));
+
+        let big_blind_index = pre_flop.post_forced_bets();
+
+        assert_eq!(1, big_blind_index);
+        assert_eq!(5, pre_flop.active_players[0].state.chips_bet_in_stage);
+        let game_loop = pre_flop.create_game_loop(big_blind_index);
+        assert_eq!(Some(PlayerId(1)), game_loop.button);
+        assert_eq!(0, game_loop.current_player_index);
+    }
+
+    #[test]
+    fn dead_small_blind_is_not_posted() {
+        let mut pre_flop = create_pre_flop(4, BlindLevel::new(5, 10));
+        pre_flop.table.bust_player(PlayerId(2));
+        pre_flop.table.start_hand();
+        pre_flop.active_players.remove(1);
+
+        let big_blind_index = pre_flop.post_forced_bets();
+
+        assert_eq!(1, big_blind_index);
+        assert_eq!(PlayerId(3), pre_flop.active_players[1].id);
+        assert_eq!(10, pre_flop.pot.chips);
+    }
+
+    #[test]
+    fn every_player_posts_an_ante() {
+        let mut pre_flop = create_pre_flop(4, BlindLevel::new(5, 10).with_ante(2));
+
+        pre_flop.post_forced_bets();
+
+        assert_eq!(23, pre_flop.pot.chips);
+        assert_eq!(98, pre_flop.active_players[3].chips);
+        assert_eq!(0, pre_flop.active_players[3].state.chips_bet_in_stage);
+    }
+
+    #[test]
+    fn big_blind_posts_big_blind_ante() {
+        let mut pre_flop = create_pre_flop(4, BlindLevel::new(5, 10).with_big_blind_ante(10));
+
+        pre_flop.post_forced_bets();
+
+        assert_eq!(25, pre_flop.pot.chips);
+        assert_eq!(80, pre_flop.active_players[1].chips);
+        assert_eq!(100, pre_flop.active_players[3].chips);
+    }
+
+    #[test]
+    fn short_big_blind_posts_blind_before_big_blind_ante() {
+        let mut pre_flop = create_pre_flop(4, BlindLevel::new(5, 10).with_big_blind_ante(10));
+        pre_flop.active_players[1].chips = 15;
+
+        pre_flop.post_forced_bets();
+
+        assert_eq!(10, pre_flop.active_players[1].state.chips_bet_in_stage);
+        assert_eq!(0, pre_flop.active_players[1].chips);
+        assert_eq!(20, pre_flop.pot.chips);
+        assert_eq!(10, pre_flop.pot.minimum_bet);
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use crate::{
     player::{Active, Folded, Player, PlayerId},
+    texas_hold_em::{game_config::GameConfig, state::table::Table},
     Deck, Hand, Pot,
 };
 
@@ -12,34 +13,46 @@ pub struct PreRound {
     pub players: Vec<Player<Folded>>,
     pub pot: Pot,
     pub deck: Deck,
+    pub table: Table,
 }
 
 impl PreRound {
-    pub fn new(players: u8) -> Result<Self, String> {
-        if players < 2 {
-            return Err("Game requires at least two players".to_string());
-        }
-
+    pub fn new(config: GameConfig) -> Self {
         let pot = Pot::default();
-        let mut deck = Deck::new().shuffle();
+        let mut table = Table::new(config);
+        let mut deck = Deck::new().shuffle_with(&mut table.rng);
 
-        let mut folded_players = vec![];
-        let mut i = 0;
-        while i < players {
-            i += 1;
-            folded_players.push(Self::deal_player(PlayerId(i), &mut deck))
-        }
+        let players = table
+            .config
+            .player_ids()
+            .into_iter()
+            .map(|id| Self::deal_player(id, table.config.starting_stack(id), &mut deck))
+            .collect();
 
-        Ok(Self {
-            players: folded_players,
+        Self {
+            players,
             pot,
             deck,
-        })
+            table,
+        }
+    }
+
+    /// Whether only one player has chips left to play with.
+    pub fn is_game_over(&self) -> bool {
+        self.players.len() < 2
+    }
+
+    pub fn start_round(mut self) -> PreFlop {
+        let deck = Deck::new().shuffle_with(&mut self.table.rng);
+        self.start_round_with_deck(deck)
     }
 
-    pub fn start_round(self) -> PreFlop {
+    /// Starts a round dealt from the given deck instead of a shuffled one.
+    ///
+    /// With a [`Deck::stacked`] deck this sets up the hole cards and board of a round.
+    pub fn start_round_with_deck(mut self, mut deck: Deck) -> PreFlop {
         self.print_stage_info();
-        let mut deck = Deck::new().shuffle();
+        self.table.start_hand();
         let active_players = self
             .players
             .into_iter()
@@ -52,6 +65,7 @@ impl PreRound {
             folded_players,
             pot,
             deck,
+            table: self.table,
         }
     }
 
@@ -67,9 +81,9 @@ impl PreRound {
         }
     }
 
-    fn deal_player(id: PlayerId, deck: &mut Deck) -> Player<Folded> {
+    fn deal_player(id: PlayerId, chips: u32, deck: &mut Deck) -> Player<Folded> {
         let cards = [deck.draw().unwrap(), deck.draw().unwrap()];
-        Player::<Folded>::new(id, Hand::new(cards), 100)
+        Player::<Folded>::new(id, Hand::new(cards), chips)
     }
 }
 
@@ -78,3 +92,123 @@ impl Display for PreRound {
         write!(f, "Pre-Round - Players: {}", self.players.len())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        player::PlayerId,
+        poker::card::parse_cards,
+        texas_hold_em::{
+            evaluation::{bitmask_evaluator::BitmaskEvaluator, hand_rank::HandRank},
+            game_config::GameConfig,
+            player_driver::{LegalAction, Move, PlayerDriver, PlayerDrivers},
+            player_view::PlayerView,
+            state::advancement::Advancement,
+        },
+        Card, Deck, Hand,
+    };
+
+    use super::PreRound;
+
+    /// Calls every bet, and checks when there is nothing to call.
+    struct CallingPlayer;
+
+    impl PlayerDriver for CallingPlayer {
+        fn determine_move(&mut self, view: &PlayerView) -> Move {
+            match view.legal_actions.contains(&LegalAction::Check) {
+                true => Move::Check,
+                false => Move::Call,
+            }
+        }
+    }
+
+    fn next_stage<Stage>(advancement: Advancement<Stage>) -> Stage {
+        match advancement {
+            Advancement::NextStage(stage) => stage,
+            Advancement::Finished(_) => panic!("Round should not finish before the showdown"),
+        }
+    }
+
+    fn seeded_pre_round(seed: u64) -> PreRound {
+        PreRound::new(GameConfig::builder().players(4).seed(seed).build().unwrap())
+    }
+
+    #[test]
+    fn same_seed_deals_the_same_round() {
+        let first = seeded_pre_round(42).start_round();
+        let second = seeded_pre_round(42).start_round();
+        assert_eq!(first.active_players, second.active_players);
+        assert_eq!(first.deck, second.deck);
+    }
+
+    #[test]
+    fn different_seeds_deal_different_rounds() {
+        let first = seeded_pre_round(42).start_round();
+        let second = seeded_pre_round(43).start_round();
+        assert_ne!(first.deck, second.deck);
+    }
+
+    #[test]
+    fn reshuffles_for_each_round() {
+        let pre_round = seeded_pre_round(42);
+        let initial_deck = pre_round.deck.clone();
+        let pre_flop = pre_round.start_round();
+        assert_ne!(
+            initial_deck.cards[..initial_deck.cards.len() - 8],
+            pre_flop.deck.cards[..]
+        );
+    }
+
+    #[test]
+    fn plays_stacked_round_to_showdown() {
+        let pre_round = PreRound::new(GameConfig::builder().players(3).build().unwrap());
+        let mut drivers: PlayerDrivers = pre_round
+            .players
+            .iter()
+            .map(|player| {
+                let driver: Box<dyn PlayerDriver> = Box::new(CallingPlayer);
+                (player.id, driver)
+            })
+            .collect();
+        let hands: Vec<Hand> = ["AsKs", "7c2d", "QhQd"]
+            .iter()
+            .map(|hand| hand.parse().unwrap())
+            .collect();
+        let board: Vec<Card> = parse_cards("Qs Js Ts 3h 4c").unwrap();
+        let deck = Deck::stacked(&hands, &board).unwrap();
+
+        let pre_flop = pre_round.start_round_with_deck(deck);
+        assert_eq!(
+            hands,
+            pre_flop
+                .active_players
+                .iter()
+                .map(|player| player.hand)
+                .collect::<Vec<Hand>>()
+        );
+        let flop = next_stage(pre_flop.advance(&mut drivers));
+        assert_eq!(board[..3], flop.cards);
+        let turn = next_stage(flop.advance(&mut drivers));
+        let river = next_stage(turn.advance(&mut drivers));
+        let showdown = next_stage(river.advance(&mut drivers));
+        assert_eq!(board[..], showdown.cards);
+
+        let finished = showdown.finish(&BitmaskEvaluator);
+        assert_eq!(
+            Some(HandRank::StraightFlush),
+            finished.made_hands[0]
+                .description()
+                .map(|description| description.hand_rank)
+        );
+        let next_round = finished.payout();
+        let chips: Vec<(PlayerId, u32)> = next_round
+            .players
+            .iter()
+            .map(|player| (player.id, player.chips))
+            .collect();
+        assert_eq!(
+            vec![(PlayerId(1), 120), (PlayerId(2), 90), (PlayerId(3), 90)],
+            chips
+        );
+    }
+}
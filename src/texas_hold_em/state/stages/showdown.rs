@@ -1,8 +1,15 @@
 use std::fmt::Display;
 
 use crate::{
-    player::{Active, Folded, Player},
-    texas_hold_em::evaluation::evaluator::{Evaluator, HandVal},
+    player::{Active, Folded, Player, PlayerId},
+    poker::pot::SidePot,
+    texas_hold_em::{
+        evaluation::{
+            evaluator::{Evaluator, HandVal},
+            hand_description::HandDescription,
+        },
+        state::table::Table,
+    },
     Card, Deck, Pot,
 };
 
@@ -13,6 +20,7 @@ pub struct Showdown {
     pub folded_players: Vec<Player<Folded>>,
     pub pot: Pot,
     pub deck: Deck,
+    pub table: Table,
     pub cards: [Card; 5],
 }
 
@@ -22,57 +30,115 @@ pub struct PlayerRankPair<'player> {
     pub rank: HandVal,
 }
 
+/// The five cards a player shows down, and the value of their hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MadeHand {
+    pub player: PlayerId,
+    pub value: HandVal,
+    pub cards: [Card; 5],
+}
+
+impl MadeHand {
+    /// The hand described in words, if the evaluator's value can be decoded.
+    pub fn description(&self) -> Option<HandDescription> {
+        HandDescription::try_from(self.value).ok()
+    }
+}
+
+impl Display for MadeHand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [first, second, third, fourth, fifth] = self.cards;
+        write!(f, "{first} {second} {third} {fourth} {fifth}")?;
+        match self.description() {
+            Some(description) => write!(f, " - {description}"),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Showdown {
-    pub fn finish(mut self, evaluator: &Box<dyn Evaluator>) -> Finished {
-        self.print_stage_info();
+    pub fn finish(self, evaluator: &dyn Evaluator) -> Finished {
+        let made_hands = self.determine_made_hands(evaluator);
+        self.print_stage_info(&made_hands);
 
-        self.fold_losers(evaluator);
+        let pots = self.award_pots(evaluator);
 
         let active_players = self.active_players;
         let folded_players = self.folded_players;
-        let pot = self.pot;
         let deck = self.deck.clone();
+        let table = self.table;
 
         Finished {
             active_players,
             folded_players,
-            pot,
+            pots,
+            made_hands,
             deck,
+            table,
         }
     }
 
-    pub fn determine_player_ranks(&self, evaluator: &Box<dyn Evaluator>) -> Vec<PlayerRankPair> {
+    /// The best five cards of each active player, using their hole cards and the board.
+    ///
+    /// Players whose cards cannot be evaluated, because a card is repeated, are left out.
+    pub fn determine_made_hands(&self, evaluator: &dyn Evaluator) -> Vec<MadeHand> {
         self.active_players
             .iter()
-            .map(|player| Showdown::determine_player_rank(self.cards, player, &evaluator))
+            .filter_map(|player| {
+                let PlayerRankPair { rank, .. } =
+                    Showdown::determine_player_rank(self.cards, player, evaluator);
+                let (cards, _) =
+                    evaluator.best_five_cards(&Showdown::seven_cards(self.cards, player))?;
+                Some(MadeHand {
+                    player: player.id,
+                    value: rank,
+                    cards,
+                })
+            })
+            .collect()
+    }
+
+    pub fn determine_player_ranks(&self, evaluator: &dyn Evaluator) -> Vec<PlayerRankPair<'_>> {
+        self.active_players
+            .iter()
+            .map(|player| Showdown::determine_player_rank(self.cards, player, evaluator))
             .collect::<Vec<PlayerRankPair>>()
     }
 
-    fn fold_losers(&mut self, evaluator: &Box<dyn Evaluator>) {
+    /// Splits the pot into side pots, leaving only the best hands eligible for each.
+    fn award_pots(&self, evaluator: &dyn Evaluator) -> Vec<SidePot> {
         let player_ranks = self.determine_player_ranks(evaluator);
-        let high_score = player_ranks.iter().map(|p| p.rank).max().unwrap();
+        let mut pots = self.pot.side_pots(&self.active_players);
 
-        let losers = player_ranks
-            .iter()
-            .filter(|p| p.rank < high_score)
-            .map(|r| *r.player)
-            .collect::<Vec<Player<Active>>>();
+        for pot in pots.iter_mut() {
+            let eligible_ranks = player_ranks
+                .iter()
+                .filter(|p| pot.eligible_players.contains(&p.player.id))
+                .collect::<Vec<&PlayerRankPair>>();
+            let high_score = eligible_ranks.iter().map(|p| p.rank).max();
 
-        self.active_players
-            .retain(|player| !losers.contains(player));
-        let mut losers = losers
-            .iter()
-            .map(|loser| loser.fold())
-            .collect::<Vec<Player<Folded>>>();
-        self.folded_players.append(&mut losers);
+            pot.eligible_players = eligible_ranks
+                .iter()
+                .filter(|p| Some(p.rank) == high_score)
+                .map(|p| p.player.id)
+                .collect();
+        }
+
+        pots
     }
 
     pub fn determine_player_rank<'player>(
         community_cards: [Card; 5],
         player: &'player Player<Active>,
-        evaluator: &Box<dyn Evaluator>,
+        evaluator: &dyn Evaluator,
     ) -> PlayerRankPair<'player> {
-        let cards = [
+        let cards = Showdown::seven_cards(community_cards, player);
+        let rank = evaluator.evaluate_hand(&cards);
+        PlayerRankPair { player, rank }
+    }
+
+    fn seven_cards(community_cards: [Card; 5], player: &Player<Active>) -> [Card; 7] {
+        [
             player.hand.cards[0],
             player.hand.cards[1],
             community_cards[0],
@@ -80,16 +146,21 @@ impl Showdown {
             community_cards[2],
             community_cards[3],
             community_cards[4],
-        ];
-        let rank = evaluator.evaluate_hand(&cards);
-        PlayerRankPair { player, rank }
+        ]
     }
 
-    fn print_stage_info(&self) {
+    fn print_stage_info(&self, made_hands: &[MadeHand]) {
         println!("{}", self);
         for player in self.active_players.iter() {
             println!("{}", player)
         }
+        for made_hand in made_hands {
+            println!(
+                "{} shows {}",
+                self.table.player_name(made_hand.player),
+                made_hand
+            )
+        }
     }
 }
 
@@ -108,3 +179,125 @@ impl Display for Showdown {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        player::{Active, Player, PlayerId},
+        texas_hold_em::{
+            evaluation::{
+                bitmask_evaluator::BitmaskEvaluator,
+                evaluator::{Evaluator, HandVal},
+                hand_rank::HandRank,
+            },
+            game_config::GameConfig,
+            state::table::Table,
+        },
+        Card, Deck, Hand, Pot, Rank, Suit,
+    };
+
+    use super::{MadeHand, Showdown};
+
+    /// Scores hands by the rank of the first hole card.
+    struct HighCardEvaluator {}
+    impl Evaluator for HighCardEvaluator {
+        fn evaluate_hand(&self, cards: &[Card; 7]) -> HandVal {
+            HandVal(cards[0].rank as u32)
+        }
+    }
+
+    fn create_player(id: u8, rank: Rank, chips: u32) -> Player<Active> {
+        let hand = Hand::new([
+            Card::new(Suit::Spade, rank),
+            Card::new(Suit::Heart, Rank::Two),
+        ]);
+        Player::<Active>::new(PlayerId(id), hand, chips)
+    }
+
+    #[test]
+    fn awards_each_side_pot_to_best_eligible_hand() {
+        let mut players = vec![
+            create_player(1, Rank::Ace, 20),
+            create_player(2, Rank::King, 100),
+            create_player(3, Rank::Queen, 100),
+        ];
+        let mut pot = Pot::default();
+        players[0].all_in(&mut pot);
+        players[1].bet(50, &mut pot).unwrap();
+        players[2].bet(50, &mut pot).unwrap();
+
+        let mut deck = Deck::new();
+        let cards = [(); 5].map(|_| deck.draw().unwrap());
+        let showdown = Showdown {
+            active_players: players,
+            folded_players: vec![],
+            pot,
+            deck,
+            table: Table::new(GameConfig::builder().players(3).build().unwrap()),
+            cards,
+        };
+
+        let finished = showdown.finish(&HighCardEvaluator {});
+        let pre_round = finished.payout();
+
+        let chips = |id: u8| {
+            pre_round
+                .players
+                .iter()
+                .find(|player| player.id == PlayerId(id))
+                .unwrap()
+                .chips
+        };
+        assert_eq!(60, chips(1));
+        assert_eq!(110, chips(2));
+        assert_eq!(50, chips(3));
+    }
+
+    #[test]
+    fn records_best_five_cards_of_each_player() {
+        let players = vec![
+            create_player(1, Rank::Ace, 100),
+            create_player(2, Rank::Seven, 100),
+        ];
+        let cards = [
+            Card::new(Suit::Spade, Rank::King),
+            Card::new(Suit::Spade, Rank::Queen),
+            Card::new(Suit::Spade, Rank::Jack),
+            Card::new(Suit::Spade, Rank::Ten),
+            Card::new(Suit::Club, Rank::Seven),
+        ];
+        let showdown = Showdown {
+            active_players: players,
+            folded_players: vec![],
+            pot: Pot::default(),
+            deck: Deck::new(),
+            table: Table::default(),
+            cards,
+        };
+
+        let finished = showdown.finish(&BitmaskEvaluator);
+
+        let royal_flush = &finished.made_hands[0];
+        assert_eq!(PlayerId(1), royal_flush.player);
+        assert_eq!(
+            [
+                Card::new(Suit::Spade, Rank::Ace),
+                Card::new(Suit::Spade, Rank::King),
+                Card::new(Suit::Spade, Rank::Queen),
+                Card::new(Suit::Spade, Rank::Jack),
+                Card::new(Suit::Spade, Rank::Ten),
+            ],
+            royal_flush.cards
+        );
+        assert_eq!("A♠ K♠ Q♠ J♠ 10♠ - Royal flush", royal_flush.to_string());
+        let MadeHand { cards, .. } = finished.made_hands[1];
+        assert_eq!(
+            Some(HandRank::Flush),
+            finished.made_hands[1]
+                .description()
+                .map(|description| description.hand_rank)
+        );
+        assert!(cards.contains(&Card::new(Suit::Spade, Rank::Seven)));
+        assert!(!cards.contains(&Card::new(Suit::Club, Rank::Seven)));
+    }
+}
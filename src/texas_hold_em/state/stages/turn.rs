@@ -3,9 +3,11 @@ use std::fmt::Display;
 use super::super::{
     advancement::Advancement,
     game_loop::{GameLoop, StageOutcome},
+    table::Table,
 };
 use crate::{
     player::{Active, Folded, Player},
+    texas_hold_em::player_driver::PlayerDrivers,
     Card, Deck, Pot,
 };
 
@@ -17,11 +19,12 @@ pub struct Turn {
     pub folded_players: Vec<Player<Folded>>,
     pub pot: Pot,
     pub deck: Deck,
+    pub table: Table,
     pub cards: [Card; 4],
 }
 
 impl Turn {
-    pub fn advance(mut self) -> Advancement<River> {
+    pub fn advance(mut self, drivers: &mut PlayerDrivers) -> Advancement<River> {
         self.print_stage_info();
         let mut deck = self.deck;
         let cards = [
@@ -34,7 +37,7 @@ impl Turn {
 
         self.deck = deck;
         let game_loop = self.create_game_loop();
-        let stage_outcome = game_loop.do_stage();
+        let stage_outcome = game_loop.do_stage(drivers);
 
         match stage_outcome {
             StageOutcome::NextStage(game_loop) => Advancement::NextStage(River {
@@ -42,29 +45,36 @@ impl Turn {
                 folded_players: game_loop.folded_players,
                 pot: game_loop.pot,
                 deck: self.deck,
+                table: self.table,
                 cards,
             }),
-            StageOutcome::Finished(game_loop) => Advancement::Finished(Finished {
+            StageOutcome::Finished(game_loop) => Advancement::Finished(Box::new(Finished {
+                pots: game_loop.pot.side_pots(&game_loop.active_players),
                 active_players: game_loop.active_players,
                 folded_players: game_loop.folded_players,
-                pot: game_loop.pot,
+                made_hands: vec![],
                 deck: self.deck,
-            }),
+                table: self.table,
+            })),
         }
     }
 
     fn create_game_loop(&self) -> GameLoop {
         let active_players = self.active_players.clone();
         let folded_players = self.folded_players.clone();
-        let button_index = active_players.len() - 1; // TODO Bump each round
-        let current_player_index = 0;
-        let pot = self.pot;
+        let button = self.table.button_player();
+        let current_player_index = self.table.first_to_act_after_button(&active_players);
+        let pot = self.pot.clone();
         GameLoop {
             active_players,
             folded_players,
-            button_index,
+            button,
             current_player_index,
             pot,
+            minimum_raise: self.table.big_bet(),
+            betting_structure: self.table.config.betting_structure,
+            bets_in_stage: 0,
+            community_cards: self.cards.to_vec(),
         }
     }
 
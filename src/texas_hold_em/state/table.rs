@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use crate::{
+    player::{Active, Player, PlayerId},
+    texas_hold_em::game_config::{BettingStructure, GameConfig},
+    BlindLevel, ShuffleRng,
+};
+
+/// The state of the table that carries over from one round to the next.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub config: Arc<GameConfig>,
+    /// The blinds for the round currently being played.
+    pub blinds: BlindLevel,
+    pub hands_played: u32,
+    /// The player in each seat, or `None` if the seat's player has busted out.
+    pub seats: Vec<Option<PlayerId>>,
+    /// The seats of the button and blinds for the round currently being played.
+    pub positions: Positions,
+    /// Shuffles the deck for each round, so a seeded game deals the same cards every time.
+    pub rng: ShuffleRng,
+}
+
+/// Seat indices of the button and blinds.
+///
+/// Uses the dead button rule, so the button and small blind seats may be empty once players bust out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Positions {
+    pub button: usize,
+    pub small_blind: usize,
+    pub big_blind: usize,
+}
+
+impl Table {
+    /// Seats the players in order, starting from seat zero.
+    pub fn new(config: GameConfig) -> Self {
+        let blinds = config.blind_structure.level_for_hand(0);
+        let seats = config.player_ids().into_iter().map(Some).collect();
+        let rng = ShuffleRng::new(config.rng_source);
+        Table {
+            config: Arc::new(config),
+            blinds,
+            hands_played: 0,
+            seats,
+            positions: Positions::default(),
+            rng,
+        }
+    }
+
+    /// Moves on to the next hand, taking the blinds from the blind structure and moving the button.
+    pub fn start_hand(&mut self) {
+        self.blinds = self
+            .config
+            .blind_structure
+            .level_for_hand(self.hands_played);
+        self.positions = match self.hands_played {
+            0 => self.first_positions(),
+            _ => self.next_positions(),
+        };
+        self.hands_played += 1;
+    }
+
+    /// The bet size for the pre-flop and flop, and the minimum bet in no-limit and pot-limit games.
+    pub fn small_bet(&self) -> u32 {
+        self.blinds.big_blind
+    }
+
+    /// The bet size for the turn and river, which doubles in fixed-limit games.
+    pub fn big_bet(&self) -> u32 {
+        match self.config.betting_structure {
+            BettingStructure::FixedLimit => self.blinds.big_blind * 2,
+            _ => self.blinds.big_blind,
+        }
+    }
+
+    pub fn player_name(&self, player: PlayerId) -> &str {
+        self.config.player_name(player)
+    }
+
+    pub fn seat_of(&self, player: PlayerId) -> Option<usize> {
+        self.seats.iter().position(|seat| *seat == Some(player))
+    }
+
+    /// The player on the button, or `None` if it is a dead button.
+    pub fn button_player(&self) -> Option<PlayerId> {
+        self.seats.get(self.positions.button).copied().flatten()
+    }
+
+    /// The player posting the small blind, or `None` if it is a dead small blind.
+    pub fn small_blind_player(&self) -> Option<PlayerId> {
+        self.seats
+            .get(self.positions.small_blind)
+            .copied()
+            .flatten()
+    }
+
+    pub fn big_blind_player(&self) -> Option<PlayerId> {
+        self.seats.get(self.positions.big_blind).copied().flatten()
+    }
+
+    /// Empties the seat of a player who has run out of chips.
+    pub fn bust_player(&mut self, player: PlayerId) {
+        if let Some(seat) = self.seat_of(player) {
+            self.seats[seat] = None;
+        }
+    }
+
+    pub fn occupied_seat_count(&self) -> usize {
+        self.seats.iter().filter(|seat| seat.is_some()).count()
+    }
+
+    /// Seats clockwise from the button, starting with the seat after it.
+    ///
+    /// Used to order players by distance to the dealer.
+    pub fn distance_from_button(&self, player: PlayerId) -> usize {
+        let seat_count = self.seats.len();
+        match self.seat_of(player) {
+            Some(seat) => (seat + seat_count - self.positions.button - 1) % seat_count,
+            None => seat_count,
+        }
+    }
+
+    /// The index of the first player to act after the button, for `players` sorted in seat order.
+    pub fn first_to_act_after_button(&self, players: &[Player<Active>]) -> usize {
+        players
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, player)| self.distance_from_button(player.id))
+            .map(|(index, _)| index)
+            .unwrap_or_default()
+    }
+
+    fn first_positions(&self) -> Positions {
+        let small_blind = self.next_occupied_seat(self.seats.len() - 1);
+        let big_blind = self.next_occupied_seat(small_blind);
+        let button = match self.occupied_seat_count() {
+            2 => small_blind,
+            _ => self.previous_occupied_seat(small_blind),
+        };
+        Positions {
+            button,
+            small_blind,
+            big_blind,
+        }
+    }
+
+    /// The big blind always moves on to the next player, with the button and small blind following behind it.
+    fn next_positions(&self) -> Positions {
+        let previous = self.positions;
+        let big_blind = self.next_occupied_seat(previous.big_blind);
+
+        // Heads up the button posts the small blind, and acts first before the flop
+        if self.occupied_seat_count() == 2 {
+            let button = self.next_occupied_seat(big_blind);
+            return Positions {
+                button,
+                small_blind: button,
+                big_blind,
+            };
+        }
+
+        Positions {
+            button: previous.small_blind,
+            small_blind: previous.big_blind,
+            big_blind,
+        }
+    }
+
+    fn next_occupied_seat(&self, seat: usize) -> usize {
+        let seat_count = self.seats.len();
+        (1..=seat_count)
+            .map(|offset| (seat + offset) % seat_count)
+            .find(|&next| self.seats[next].is_some())
+            .unwrap_or(seat)
+    }
+
+    fn previous_occupied_seat(&self, seat: usize) -> usize {
+        let seat_count = self.seats.len();
+        (1..=seat_count)
+            .map(|offset| (seat + seat_count - offset) % seat_count)
+            .find(|&previous| self.seats[previous].is_some())
+            .unwrap_or(seat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        player::{Active, Player, PlayerId},
+        texas_hold_em::game_config::GameConfig,
+        BlindLevel, BlindStructure, Deck, Hand,
+    };
+
+    use super::{Positions, Table};
+
+    fn create_table(players: u8) -> Table {
+        Table::new(GameConfig::builder().players(players).build().unwrap())
+    }
+
+    fn positions(button: usize, small_blind: usize, big_blind: usize) -> Positions {
+        Positions {
+            button,
+            small_blind,
+            big_blind,
+        }
+    }
+
+    #[test]
+    fn blinds_follow_the_blind_structure() {
+        let structure = BlindStructure::increasing_every(
+            2,
+            vec![BlindLevel::new(5, 10), BlindLevel::new(10, 20)],
+        )
+        .unwrap();
+        let mut table = Table::new(
+            GameConfig::builder()
+                .blind_structure(structure)
+                .build()
+                .unwrap(),
+        );
+
+        let blinds = (0..4)
+            .map(|_| {
+                table.start_hand();
+                table.blinds.big_blind
+            })
+            .collect::<Vec<u32>>();
+
+        assert_eq!(vec![10, 10, 20, 20], blinds);
+        assert_eq!(4, table.hands_played);
+    }
+
+    #[test]
+    fn button_moves_each_hand() {
+        let mut table = create_table(3);
+
+        let mut hands = vec![];
+        for _ in 0..4 {
+            table.start_hand();
+            hands.push(table.positions);
+        }
+
+        assert_eq!(
+            vec![
+                positions(2, 0, 1),
+                positions(0, 1, 2),
+                positions(1, 2, 0),
+                positions(2, 0, 1)
+            ],
+            hands
+        );
+    }
+
+    #[test]
+    fn button_posts_small_blind_heads_up() {
+        let mut table = create_table(2);
+
+        table.start_hand();
+        assert_eq!(positions(0, 0, 1), table.positions);
+        table.start_hand();
+        assert_eq!(positions(1, 1, 0), table.positions);
+    }
+
+    #[test]
+    fn button_is_dead_when_small_blind_busts() {
+        let mut table = create_table(4);
+        table.start_hand();
+        assert_eq!(positions(3, 0, 1), table.positions);
+
+        table.bust_player(PlayerId(1));
+        table.start_hand();
+
+        assert_eq!(positions(0, 1, 2), table.positions);
+        assert_eq!(None, table.button_player());
+        assert_eq!(Some(PlayerId(2)), table.small_blind_player());
+        assert_eq!(Some(PlayerId(3)), table.big_blind_player());
+    }
+
+    #[test]
+    fn small_blind_is_dead_when_big_blind_busts() {
+        let mut table = create_table(4);
+        table.start_hand();
+
+        table.bust_player(PlayerId(2));
+        table.start_hand();
+
+        assert_eq!(positions(0, 1, 2), table.positions);
+        assert_eq!(Some(PlayerId(1)), table.button_player());
+        assert_eq!(None, table.small_blind_player());
+        assert_eq!(Some(PlayerId(3)), table.big_blind_player());
+    }
+
+    #[test]
+    fn big_blind_alternates_when_going_heads_up() {
+        let mut table = create_table(3);
+        table.start_hand();
+        assert_eq!(positions(2, 0, 1), table.positions);
+
+        table.bust_player(PlayerId(3));
+        table.start_hand();
+
+        assert_eq!(positions(1, 1, 0), table.positions);
+    }
+
+    #[test]
+    fn orders_players_by_distance_from_button() {
+        let mut table = create_table(4);
+        table.start_hand();
+        table.start_hand();
+
+        assert_eq!(0, table.distance_from_button(PlayerId(2)));
+        assert_eq!(2, table.distance_from_button(PlayerId(4)));
+        assert_eq!(3, table.distance_from_button(PlayerId(1)));
+    }
+
+    #[test]
+    fn big_blind_acts_first_after_the_flop_heads_up() {
+        let mut table = create_table(2);
+        table.start_hand();
+        let mut deck = Deck::new();
+        let players = [PlayerId(1), PlayerId(2)].map(|id| {
+            let hand = Hand::new([deck.draw().unwrap(), deck.draw().unwrap()]);
+            Player::<Active>::new(id, hand, 100)
+        });
+
+        assert_eq!(1, table.first_to_act_after_button(&players));
+    }
+}